@@ -0,0 +1,29 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use fibble::{Wordle, best_information_guess, remaining_secrets_count};
+
+fn best_guess_benchmark(c: &mut Criterion) {
+    let fresh = Wordle::new("awake").expect("valid secret");
+
+    // Two deliberately weak guesses leave about 50 candidates, a typical turn-three state.
+    let mut mid_game = Wordle::new("awake").expect("valid secret");
+    for guess in ["jiffy", "woozy"] {
+        mid_game.submit_guess(guess).expect("allowed guess");
+    }
+    assert_eq!(remaining_secrets_count(&mid_game), 48);
+
+    let mut group = c.benchmark_group("best_information_guess");
+    group.sample_size(10);
+
+    group.bench_function("fresh_game", |b| {
+        b.iter(|| best_information_guess(black_box(&fresh)).expect("candidates remain"));
+    });
+
+    group.bench_function("mid_game", |b| {
+        b.iter(|| best_information_guess(black_box(&mid_game)).expect("candidates remain"));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, best_guess_benchmark);
+criterion_main!(benches);
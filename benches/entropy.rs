@@ -1,5 +1,6 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use fibble::{allowed_words, analyze_guess_against, secret_words};
+use fibble::{Word, allowed_words, analyze_guess_against, analyze_word_against, secret_words};
+use fibble_core::{compute_pattern_digits_packed, compute_pattern_digits_scalar, encode_pattern};
 
 fn entropy_benchmark(c: &mut Criterion) {
     let secrets = secret_words();
@@ -14,6 +15,59 @@ fn entropy_benchmark(c: &mut Criterion) {
         });
     });
 
+    let packed_guesses = guesses.map(|guess| Word::parse(guess).expect("valid word"));
+    let all_secrets: Vec<Word> = secrets
+        .iter()
+        .map(|secret| Word::parse(secret).expect("valid word"))
+        .collect();
+
+    // The same scan with the words parsed up front, skipping per-call validation and the
+    // string wrapper's checks.
+    c.bench_function("analyze_word/all_secrets", |b| {
+        b.iter(|| {
+            for guess in packed_guesses {
+                black_box(analyze_word_against(
+                    black_box(guess),
+                    all_secrets.iter().copied(),
+                ));
+            }
+        });
+    });
+
+    let packed_secrets: Vec<Word> = secrets
+        .iter()
+        .take(500)
+        .map(|secret| Word::parse(secret).expect("valid word"))
+        .collect();
+
+    // Guess-major fixed-size pairs, the order and shape the game scores them in.
+    let pairs: Vec<([u8; 5], [u8; 5])> = packed_guesses
+        .iter()
+        .flat_map(|guess| {
+            packed_secrets
+                .iter()
+                .map(|secret| (*secret.as_bytes(), *guess.as_bytes()))
+        })
+        .collect();
+    c.bench_function("pattern_digits/scalar", |b| {
+        b.iter(|| {
+            let mut counts = [0usize; 243];
+            for (secret, guess) in &pairs {
+                counts[encode_pattern(&compute_pattern_digits_scalar::<5>(secret, guess))] += 1;
+            }
+            black_box(counts)
+        });
+    });
+    c.bench_function("pattern_digits/packed", |b| {
+        b.iter(|| {
+            let mut counts = [0usize; 243];
+            for (secret, guess) in &pairs {
+                counts[encode_pattern(&compute_pattern_digits_packed::<5>(secret, guess))] += 1;
+            }
+            black_box(counts)
+        });
+    });
+
     let allowed = allowed_words();
     let random_guess = allowed
         .get(1234)
@@ -0,0 +1,28 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use fibble::{Wordle, allowed_words, analyze_guess_against, best_information_guess, secret_words};
+
+fn parallel_scan_benchmark(c: &mut Criterion) {
+    let secrets: Vec<&str> = secret_words().iter().map(|word| word.as_str()).collect();
+    let game = Wordle::new("cigar").expect("valid secret");
+    let mut group = c.benchmark_group("full_scan");
+    group.sample_size(10);
+
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            allowed_words()
+                .iter()
+                .filter_map(|guess| analyze_guess_against(guess, secrets.iter().copied()).ok())
+                .max_by(|a, b| a.entropy_bits().total_cmp(&b.entropy_bits()))
+                .expect("allowed words not empty")
+        });
+    });
+
+    group.bench_function("parallel", |b| {
+        b.iter(|| best_information_guess(black_box(&game)).expect("allowed words not empty"));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parallel_scan_benchmark);
+criterion_main!(benches);
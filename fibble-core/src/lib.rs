@@ -0,0 +1,393 @@
+//! The pure scoring and entropy math behind `fibble`, usable without the standard library.
+//!
+//! Everything here needs only `alloc`, so it builds for WASM and embedded targets. Word lists,
+//! random lies, and the game itself live in the `fibble` crate. Enable the `std` feature to
+//! take logarithms from `std` instead of `libm`, and the `packed` feature to score patterns
+//! with the bitmask-based [`compute_pattern_digits_packed`].
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use core::fmt;
+
+const ALPHABET_SIZE: usize = 26;
+
+/// Digit for a letter that is not in the secret (or whose copies are all accounted for).
+pub const PATTERN_ABSENT: u8 = 0;
+/// Digit for a letter that is in the secret at another position.
+pub const PATTERN_PRESENT: u8 = 1;
+/// Digit for a letter in the right position.
+pub const PATTERN_CORRECT: u8 = 2;
+
+/// The number of distinct color patterns for words of the given length.
+pub const fn pattern_space(word_length: usize) -> usize {
+    3usize.pow(word_length as u32)
+}
+
+/// Scores `guess` against `secret`, both `N` uppercase ASCII letters, into one digit per tile.
+///
+/// Greens are assigned first; each remaining guess letter is yellow only while the secret has
+/// unmatched copies of it left, so duplicate letters score like the official game. This is
+/// [`compute_pattern_digits_packed`] with the `packed` feature and
+/// [`compute_pattern_digits_scalar`] otherwise; both give identical results.
+#[inline]
+pub fn compute_pattern_digits<const N: usize>(secret: &[u8], guess: &[u8]) -> [u8; N] {
+    #[cfg(feature = "packed")]
+    {
+        compute_pattern_digits_packed::<N>(secret, guess)
+    }
+    #[cfg(not(feature = "packed"))]
+    {
+        compute_pattern_digits_scalar::<N>(secret, guess)
+    }
+}
+
+/// Why [`try_compute_pattern_digits`] refused to score a pair of words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreError {
+    /// The secret or the guess is not `expected` bytes long.
+    WrongLength { expected: usize, found: usize },
+    /// A byte outside `A`-`Z`, such as a lowercase letter or part of a multi-byte character.
+    NotALetter { position: usize, byte: u8 },
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoreError::WrongLength { expected, found } => write!(
+                f,
+                "expected a {expected}-letter word, but found {found} bytes"
+            ),
+            ScoreError::NotALetter { position, byte } => write!(
+                f,
+                "byte {byte:#04x} at position {position} is not an uppercase letter A-Z"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ScoreError {}
+
+/// Like [`compute_pattern_digits`], but checks both words first instead of trusting the caller.
+///
+/// The secret is checked before the guess, and each is checked for length before letters, so
+/// the error describes the first problem found. Use this for bytes that have not already been
+/// normalized, such as words read back from a file.
+pub fn try_compute_pattern_digits<const N: usize>(
+    secret: &[u8],
+    guess: &[u8],
+) -> Result<[u8; N], ScoreError> {
+    for word in [secret, guess] {
+        if word.len() != N {
+            return Err(ScoreError::WrongLength {
+                expected: N,
+                found: word.len(),
+            });
+        }
+        if let Some(position) = word.iter().position(|byte| !byte.is_ascii_uppercase()) {
+            return Err(ScoreError::NotALetter {
+                position,
+                byte: word[position],
+            });
+        }
+    }
+    Ok(compute_pattern_digits::<N>(secret, guess))
+}
+
+/// The reference scorer: a 26-entry array counts the secret's unmatched letters.
+///
+/// The counters are `u32` so that no word length that fits in memory can overflow them.
+pub fn compute_pattern_digits_scalar<const N: usize>(secret: &[u8], guess: &[u8]) -> [u8; N] {
+    debug_assert_eq!(secret.len(), N, "secret words must be {N} letters long");
+    debug_assert_eq!(guess.len(), N, "guess words must be {N} letters long");
+
+    let mut digits = [PATTERN_ABSENT; N];
+    let mut leftovers = [0u32; ALPHABET_SIZE];
+
+    for idx in 0..N {
+        let secret_byte = secret[idx];
+        let guess_byte = guess[idx];
+        if guess_byte == secret_byte {
+            digits[idx] = PATTERN_CORRECT;
+        } else {
+            leftovers[letter_index(secret_byte)] += 1;
+        }
+    }
+
+    for idx in 0..N {
+        if digits[idx] == PATTERN_CORRECT {
+            continue;
+        }
+
+        let guess_byte = guess[idx];
+        let lookup = letter_index(guess_byte);
+        if leftovers[lookup] > 0 {
+            digits[idx] = PATTERN_PRESENT;
+            leftovers[lookup] -= 1;
+        }
+    }
+
+    digits
+}
+
+/// Like [`compute_pattern_digits_scalar`], but tracks the secret's unmatched letters in a
+/// `u32` bitmask and scores each tile without branching, so there is nothing to zero per pair
+/// and no data-dependent jumps to mispredict.
+///
+/// A bitmask only records one copy of each letter, so secrets with a repeated unmatched
+/// letter (a few percent of pairs) fall back to the scalar scorer.
+pub fn compute_pattern_digits_packed<const N: usize>(secret: &[u8], guess: &[u8]) -> [u8; N] {
+    debug_assert_eq!(secret.len(), N, "secret words must be {N} letters long");
+    debug_assert_eq!(guess.len(), N, "guess words must be {N} letters long");
+
+    let mut greens = [false; N];
+    let mut unmatched = 0u32;
+    let mut repeated = 0u32;
+    for idx in 0..N {
+        greens[idx] = guess[idx] == secret[idx];
+        // All ones for an unmatched tile, zero for a green one.
+        let keep = (greens[idx] as u32).wrapping_sub(1);
+        let bit = (1u32 << letter_index(secret[idx])) & keep;
+        repeated |= unmatched & bit;
+        unmatched |= bit;
+    }
+    if repeated != 0 {
+        return compute_pattern_digits_scalar::<N>(secret, guess);
+    }
+
+    let mut digits = [PATTERN_ABSENT; N];
+    for idx in 0..N {
+        let bit = 1u32 << letter_index(guess[idx]);
+        let yellow = (unmatched & bit != 0) & !greens[idx];
+        digits[idx] = greens[idx] as u8 * PATTERN_CORRECT + yellow as u8 * PATTERN_PRESENT;
+        unmatched &= !(bit * yellow as u32);
+    }
+
+    digits
+}
+
+/// Packs pattern digits into a base-3 code below [`pattern_space`]`(N)`.
+pub fn encode_pattern<const N: usize>(digits: &[u8; N]) -> usize {
+    digits
+        .iter()
+        .fold(0usize, |acc, digit| acc * 3 + *digit as usize)
+}
+
+/// Spells a pattern code as `word_length` tiles of `G` (correct), `Y` (present), and `B`
+/// (absent), such as `GYBBB`.
+pub fn pattern_code_to_string(mut code: usize, word_length: usize) -> String {
+    let mut chars = vec![b'B'; word_length];
+    for idx in (0..word_length).rev() {
+        let digit = code % 3;
+        code /= 3;
+        chars[idx] = match digit {
+            2 => b'G',
+            1 => b'Y',
+            _ => b'B',
+        };
+    }
+    chars.iter().map(|byte| char::from(*byte)).collect()
+}
+
+/// Computes the Shannon entropy (in bits) of a histogram of pattern counts.
+pub fn entropy_bits(counts: &[usize]) -> f64 {
+    let total = counts.iter().sum::<usize>() as f64;
+    counts.iter().fold(0.0, |acc, count| {
+        if *count == 0 {
+            acc
+        } else {
+            let probability = *count as f64 / total;
+            acc - probability * log2(probability)
+        }
+    })
+}
+
+/// Computes the Shannon entropy (in bits) of non-negative pattern weights that need not sum
+/// to one.
+pub fn weighted_entropy_bits(weights: &[f64]) -> f64 {
+    let total: f64 = weights.iter().sum();
+    weights.iter().fold(0.0, |acc, weight| {
+        if *weight <= 0.0 {
+            acc
+        } else {
+            let probability = weight / total;
+            acc - probability * log2(probability)
+        }
+    })
+}
+
+/// Maps `A`-`Z` to `0..26`. Other bytes trip the assertion in debug builds; in release they
+/// wrap into range, giving a meaningless pattern rather than a panic or an out-of-bounds index.
+fn letter_index(letter: u8) -> usize {
+    debug_assert!(
+        letter.is_ascii_uppercase(),
+        "words should use only uppercase ASCII letters"
+    );
+    letter.wrapping_sub(b'A') as usize % ALPHABET_SIZE
+}
+
+#[cfg(feature = "std")]
+fn log2(value: f64) -> f64 {
+    value.log2()
+}
+
+#[cfg(not(feature = "std"))]
+fn log2(value: f64) -> f64 {
+    libm::log2(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_letters_only_turn_yellow_while_copies_remain() {
+        let digits = compute_pattern_digits::<5>(b"ABBEY", b"BOBBY");
+        assert_eq!(
+            digits,
+            [
+                PATTERN_PRESENT,
+                PATTERN_ABSENT,
+                PATTERN_CORRECT,
+                PATTERN_ABSENT,
+                PATTERN_CORRECT
+            ]
+        );
+        assert_eq!(pattern_code_to_string(encode_pattern(&digits), 5), "YBGBG");
+    }
+
+    #[test]
+    fn packed_scoring_handles_repeated_letters() {
+        for (secret, guess) in [
+            (b"APPLE", b"ALLOT"),
+            (b"ABBEY", b"BOBBY"),
+            (b"SISSY", b"SASSY"),
+        ] {
+            assert_eq!(
+                compute_pattern_digits_packed::<5>(secret, guess),
+                compute_pattern_digits_scalar::<5>(secret, guess)
+            );
+        }
+        assert_eq!(
+            compute_pattern_digits_packed::<5>(b"APPLE", b"ALLOT"),
+            [
+                PATTERN_CORRECT,
+                PATTERN_PRESENT,
+                PATTERN_ABSENT,
+                PATTERN_ABSENT,
+                PATTERN_ABSENT
+            ]
+        );
+    }
+
+    #[test]
+    fn packed_and_scalar_scoring_agree_on_random_pairs() {
+        // A fixed xorshift keeps the test reproducible without a dev-dependency. Drawing from
+        // only six letters makes repeated letters, the tricky case, very common.
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next_word = || {
+            let mut word = [0u8; 5];
+            for letter in &mut word {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                *letter = b'A' + (state % 6) as u8;
+            }
+            word
+        };
+        for _ in 0..100_000 {
+            let secret = next_word();
+            let guess = next_word();
+            assert_eq!(
+                compute_pattern_digits_packed::<5>(&secret, &guess),
+                compute_pattern_digits_scalar::<5>(&secret, &guess),
+                "{secret:?} vs {guess:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn checked_scoring_rejects_arbitrary_bytes_without_panicking() {
+        // Arbitrary bytes of every value, so most words contain something other than A-Z.
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        let mut next_byte = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 24) as u8
+        };
+        let mut scored = 0;
+        for round in 0..100_000 {
+            // Every fourth word is forced into A-Z so some pairs get all the way to scoring.
+            let mut word = || {
+                let mut word = [0u8; 5];
+                for letter in &mut word {
+                    let byte = next_byte();
+                    *letter = if round % 4 == 0 {
+                        b'A' + byte % 26
+                    } else {
+                        byte
+                    };
+                }
+                word
+            };
+            let secret = word();
+            let guess = word();
+            let valid = secret.iter().chain(&guess).all(u8::is_ascii_uppercase);
+            match try_compute_pattern_digits::<5>(&secret, &guess) {
+                Ok(digits) => {
+                    assert!(valid, "{secret:?} vs {guess:?}");
+                    assert_eq!(digits, compute_pattern_digits_scalar::<5>(&secret, &guess));
+                    scored += 1;
+                }
+                Err(ScoreError::NotALetter { byte, .. }) => {
+                    assert!(!valid);
+                    assert!(!byte.is_ascii_uppercase());
+                }
+                Err(err) => panic!("unexpected {err:?}"),
+            }
+        }
+        assert!(scored > 0);
+    }
+
+    #[test]
+    fn checked_scoring_reports_the_first_problem() {
+        assert_eq!(
+            try_compute_pattern_digits::<5>(b"CIGAR", b"CAIRNS"),
+            Err(ScoreError::WrongLength {
+                expected: 5,
+                found: 6
+            })
+        );
+        assert_eq!(
+            try_compute_pattern_digits::<5>("CAF\u{c9}".as_bytes(), b"CAIRN"),
+            Err(ScoreError::NotALetter {
+                position: 3,
+                byte: 0xc3
+            })
+        );
+        assert_eq!(
+            try_compute_pattern_digits::<5>(b"CIGAR", b"cairn"),
+            Err(ScoreError::NotALetter {
+                position: 0,
+                byte: b'c'
+            })
+        );
+        // Long words cannot overflow the letter counters.
+        let secret = [b'A'; 300];
+        let mut guess = [b'B'; 300];
+        guess[299] = b'A';
+        let digits = try_compute_pattern_digits::<300>(&secret, &guess).unwrap();
+        assert_eq!(digits[299], PATTERN_CORRECT);
+        assert!(digits[..299].iter().all(|digit| *digit == PATTERN_ABSENT));
+    }
+
+    #[test]
+    fn entropy_of_uniform_buckets_is_their_log() {
+        assert!((entropy_bits(&[3, 0, 3, 3, 3]) - 2.0).abs() < 1e-12);
+        assert!((weighted_entropy_bits(&[2.5, 0.0, 2.5]) - 1.0).abs() < 1e-12);
+        assert_eq!(entropy_bits(&[7]), 0.0);
+    }
+}
@@ -0,0 +1,49 @@
+use fibble::{cache_dir_path, OpeningBook, OPENING_BOOK_FILE, SOLVER_OPENER};
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::path::PathBuf;
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut opener = SOLVER_OPENER.to_string();
+    let mut dir = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--opener" => opener = args.next().ok_or("missing value for --opener")?,
+            "--cache-dir" => {
+                dir = Some(PathBuf::from(
+                    args.next().ok_or("missing value for --cache-dir")?,
+                ))
+            }
+            other => return Err(format!("unknown argument: {other}").into()),
+        }
+    }
+
+    // Same lookup order as the fibble binary, so it finds the book without extra flags.
+    let dir = cache_dir_path(dir).ok_or("no cache directory available; pass --cache-dir")?;
+
+    eprintln!(
+        "Generating the opening book for {}...",
+        opener.to_ascii_uppercase()
+    );
+    let book = OpeningBook::generate(&opener)?;
+
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(OPENING_BOOK_FILE);
+    serde_json::to_writer_pretty(File::create(&path)?, &book)?;
+    println!(
+        "Wrote {} follow-ups for {} to {}",
+        book.len(),
+        book.opener(),
+        path.display()
+    );
+    Ok(())
+}
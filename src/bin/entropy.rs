@@ -1,6 +1,9 @@
-use fibble::analyze_guess;
+use fibble::{
+    allowed_words, analyze_guess_against, analyze_with_matrix, for_each_guess_analysis,
+    secret_words, Constraints, GuessEntropy, PatternMatrix,
+};
 use std::error::Error;
-use std::io::{Error as IoError, ErrorKind};
+use std::io::{self, BufRead, BufWriter, Write};
 
 fn main() {
     if let Err(err) = run() {
@@ -10,14 +13,47 @@ fn main() {
 }
 
 fn run() -> Result<(), Box<dyn Error>> {
-    let guess = std::env::args().nth(1).ok_or_else(|| {
-        IoError::new(
-            ErrorKind::InvalidInput,
-            "usage: fibble-entropy <guess word>",
-        )
-    })?;
-
-    let analysis = analyze_guess(&guess)?;
+    let mut json = false;
+    let mut stdin = false;
+    let mut csv = false;
+    let mut guess = None;
+    let mut constraints = Constraints::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--stdin" => stdin = true,
+            "--csv" => csv = true,
+            "--after" => {
+                let value = args
+                    .next()
+                    .ok_or("missing value for --after; expected GUESS:PATTERN")?;
+                constraints = apply_after(constraints, &value)?;
+            }
+            _ => guess = Some(arg),
+        }
+    }
+
+    let survivors = constraints.filter_words(secret_words().iter().map(|word| word.as_str()));
+    if survivors.is_empty() {
+        return Err("no secrets match the --after patterns".into());
+    }
+
+    if csv {
+        return write_csv(&survivors);
+    }
+
+    // Without a guess argument, analyze every word piped in on stdin.
+    let Some(guess) = guess.filter(|_| !stdin) else {
+        return run_batch(&survivors, json);
+    };
+
+    let analysis = analyze_guess_against(&guess, survivors)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&analysis)?);
+        return Ok(());
+    }
+
     println!("Guess: {}", analysis.guess());
     println!("Total secrets: {}", analysis.total_secrets());
     println!("Distinct patterns: {}", analysis.distinct_patterns());
@@ -25,3 +61,133 @@ fn run() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Reads one guess per line from stdin and prints their entropies, best first. Lines that are
+/// not allowed guesses are reported and skipped.
+fn run_batch(survivors: &[&str], json: bool) -> Result<(), Box<dyn Error>> {
+    let matrix = PatternMatrix::build();
+    let secret_indices: Vec<usize> = survivors
+        .iter()
+        .filter_map(|word| matrix.secret_index(word))
+        .collect();
+
+    let mut analyses: Vec<GuessEntropy> = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let word = line.trim();
+        if word.is_empty() {
+            continue;
+        }
+        match matrix.guess_index(&word.to_ascii_uppercase()) {
+            Some(guess_idx) => analyses.push(analyze_with_matrix(
+                &matrix,
+                guess_idx,
+                secret_indices.iter().copied(),
+            )),
+            None => eprintln!("Skipping {word:?}: not an allowed guess"),
+        }
+    }
+
+    analyses.sort_by(|a, b| {
+        b.entropy_bits()
+            .partial_cmp(&a.entropy_bits())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.guess().cmp(b.guess()))
+    });
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&analyses)?);
+        return Ok(());
+    }
+
+    println!("{:<7} {:>8} {:>9}", "Guess", "Bits", "Patterns");
+    for analysis in &analyses {
+        println!(
+            "{:<7} {:>8.4} {:>9}",
+            analysis.guess(),
+            analysis.entropy_bits(),
+            analysis.distinct_patterns()
+        );
+    }
+
+    Ok(())
+}
+
+/// One line of the `--csv` report.
+struct CsvRow {
+    guess: String,
+    entropy_bits: f64,
+    distinct_patterns: usize,
+    expected_remaining: f64,
+}
+
+/// Writes every allowed guess scored against `survivors` to stdout as CSV, best first.
+///
+/// Each analysis is reduced to its summary as soon as it is scored, so only the compact rows
+/// are held for the sort before they are streamed out.
+fn write_csv(survivors: &[&str]) -> Result<(), Box<dyn Error>> {
+    let mut rows: Vec<CsvRow> = Vec::with_capacity(allowed_words().len());
+    for_each_guess_analysis(
+        survivors,
+        None,
+        |_, _| {},
+        |analysis| {
+            rows.push(CsvRow {
+                guess: analysis.guess().to_string(),
+                entropy_bits: analysis.entropy_bits(),
+                distinct_patterns: analysis.distinct_patterns(),
+                expected_remaining: analysis.expected_remaining(),
+            })
+        },
+    )?;
+    rows.sort_by(|a, b| {
+        b.entropy_bits
+            .partial_cmp(&a.entropy_bits)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.guess.cmp(&b.guess))
+    });
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    writeln!(
+        out,
+        "guess,entropy_bits,distinct_patterns,expected_remaining"
+    )?;
+    for row in &rows {
+        writeln!(
+            out,
+            "{},{:.6},{},{:.6}",
+            csv_field(&row.guess),
+            row.entropy_bits,
+            row.distinct_patterns,
+            row.expected_remaining
+        )?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Quotes `value` for CSV when it contains a comma, quote, or line break, doubling any quotes.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Narrows `constraints` by one `--after` value such as `CRANE:BBYBB`.
+fn apply_after(constraints: Constraints, value: &str) -> Result<Constraints, Box<dyn Error>> {
+    let (guess, pattern) = value
+        .split_once(':')
+        .ok_or_else(|| format!("invalid --after value {value:?}; expected GUESS:PATTERN"))?;
+    let guess = guess.to_ascii_uppercase();
+    let pattern = pattern.to_ascii_uppercase();
+    if guess.chars().count() != pattern.chars().count() {
+        return Err(
+            format!("invalid --after value {value:?}; guess and pattern lengths differ").into(),
+        );
+    }
+    constraints
+        .observe(&guess, &pattern)
+        .map_err(|err| format!("invalid --after value {value:?}: {err}").into())
+}
@@ -0,0 +1,255 @@
+use crate::{
+    parse_pattern_digits, PatternError, Wordle, PATTERN_ABSENT, PATTERN_CORRECT, WORD_LENGTH,
+};
+use std::collections::HashMap;
+
+/// What is known about a `WORD_LENGTH`-letter secret, built up clue by clue without a game.
+///
+/// Letters are compared case-insensitively. A yellow clue is usually two calls:
+/// `require_present('A')` plus `forbid_at(pos, 'A')` for the slot where it showed. Forbidding
+/// a letter that is also required caps it at the required count, which is how a gray
+/// duplicate reads.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Constraints {
+    greens: Vec<(usize, char)>,
+    forbidden_at: Vec<(usize, char)>,
+    min_counts: HashMap<char, usize>,
+    forbidden: Vec<char>,
+}
+
+impl Constraints {
+    /// Creates a filter that accepts every word.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires `letter` at position `pos` (zero-based). Repeating a green is a no-op, so the
+    /// same tile seen on several rows still counts as one copy.
+    pub fn require_green(mut self, pos: usize, letter: char) -> Self {
+        let green = (pos, letter.to_ascii_uppercase());
+        if !self.greens.contains(&green) {
+            self.greens.push(green);
+        }
+        self
+    }
+
+    /// Requires `letter` to appear somewhere in the word.
+    pub fn require_present(self, letter: char) -> Self {
+        self.require_at_least(letter, 1)
+    }
+
+    fn require_at_least(mut self, letter: char, count: usize) -> Self {
+        let needed = self
+            .min_counts
+            .entry(letter.to_ascii_uppercase())
+            .or_default();
+        *needed = (*needed).max(count);
+        self
+    }
+
+    /// Rules out `letter` beyond any copies other clues require.
+    pub fn forbid(mut self, letter: char) -> Self {
+        let letter = letter.to_ascii_uppercase();
+        if !self.forbidden.contains(&letter) {
+            self.forbidden.push(letter);
+        }
+        self
+    }
+
+    /// Rules out `letter` at position `pos` (zero-based) only.
+    pub fn forbid_at(mut self, pos: usize, letter: char) -> Self {
+        let slot = (pos, letter.to_ascii_uppercase());
+        if !self.forbidden_at.contains(&slot) {
+            self.forbidden_at.push(slot);
+        }
+        self
+    }
+
+    /// Adds every clue from one row: the `WORD_LENGTH`-letter `guess` and the uppercase
+    /// `G`/`Y`/`B` pattern it showed, such as `GYBBB`.
+    ///
+    /// Repeated letters are counted, so a green and a yellow `L` require two of them and a
+    /// gray duplicate caps the count.
+    pub fn observe(mut self, guess: &str, pattern: &str) -> Result<Self, PatternError> {
+        let digits = parse_pattern_digits(pattern)?;
+        let mut shown: HashMap<char, usize> = HashMap::new();
+        for (pos, (letter, digit)) in guess.chars().zip(digits).enumerate() {
+            let letter = letter.to_ascii_uppercase();
+            match digit {
+                PATTERN_CORRECT => self = self.require_green(pos, letter),
+                PATTERN_ABSENT => self = self.forbid_at(pos, letter).forbid(letter),
+                _ => self = self.forbid_at(pos, letter),
+            }
+            if digit != PATTERN_ABSENT {
+                *shown.entry(letter).or_default() += 1;
+            }
+        }
+        for (letter, count) in shown {
+            self = self.require_at_least(letter, count);
+        }
+        Ok(self)
+    }
+
+    /// Whether `word` satisfies every clue. Words of the wrong length never match.
+    pub fn matches(&self, word: &str) -> bool {
+        let letters: Vec<char> = word.chars().map(|ch| ch.to_ascii_uppercase()).collect();
+        if letters.len() != WORD_LENGTH {
+            return false;
+        }
+
+        let placed = |&(pos, letter): &(usize, char)| letters.get(pos) == Some(&letter);
+        if !self.greens.iter().all(placed) || self.forbidden_at.iter().any(placed) {
+            return false;
+        }
+
+        let count = |letter: char| letters.iter().filter(|ch| **ch == letter).count();
+        if self
+            .min_counts
+            .iter()
+            .any(|(letter, needed)| count(*letter) < *needed)
+        {
+            return false;
+        }
+
+        self.forbidden
+            .iter()
+            .all(|letter| count(*letter) <= self.required_count(*letter))
+    }
+
+    /// Keeps the words that satisfy every clue, in their original order.
+    pub fn filter_words<'a>(&self, words: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+        words.filter(|word| self.matches(word)).collect()
+    }
+
+    fn required_count(&self, letter: char) -> usize {
+        let greens = self
+            .greens
+            .iter()
+            .filter(|(_, green)| *green == letter)
+            .count();
+        greens.max(self.min_counts.get(&letter).copied().unwrap_or(0))
+    }
+}
+
+impl Wordle {
+    /// Folds every row into one [`Constraints`]: the greens, the letters known present with
+    /// their minimum counts, and the letters ruled out overall or at a position.
+    ///
+    /// Only meaningful in Wordle and Absurdle mode. Fibble rows are folded as shown, lies
+    /// included, so the result may rule out the real secret.
+    pub fn constraints(&self) -> Constraints {
+        self.guesses()
+            .iter()
+            .fold(Constraints::new(), |known, row| {
+                known
+                    .observe(row.guess(), &row.pattern_string())
+                    .expect("scored rows have valid patterns")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_green_yellow_and_gray_clues() {
+        // What CAIRN shows against a secret like CIGAR, minus the I and R clues.
+        let constraints = Constraints::new()
+            .require_green(0, 'c')
+            .require_present('a')
+            .forbid_at(1, 'a')
+            .forbid('n')
+            .forbid('e');
+        let words = ["CIGAR", "CAIRN", "CHAIR", "CLEAR", "CLOTH"];
+        assert_eq!(
+            constraints.filter_words(words.into_iter()),
+            ["CIGAR", "CHAIR"]
+        );
+    }
+
+    #[test]
+    fn observe_reads_a_pattern_row() {
+        let constraints = Constraints::new().observe("cairn", "GYYYB").unwrap();
+        let words = ["CIGAR", "CAIRN", "CHAIR", "CLEAR"];
+        assert_eq!(
+            constraints.filter_words(words.into_iter()),
+            ["CIGAR", "CHAIR"]
+        );
+    }
+
+    #[test]
+    fn observe_rejects_malformed_patterns() {
+        assert_eq!(
+            Constraints::new().observe("cairn", "GYB").unwrap_err(),
+            PatternError::WrongLength {
+                expected: 5,
+                found: 3
+            }
+        );
+        assert_eq!(
+            Constraints::new().observe("cairn", "GYBXB").unwrap_err(),
+            PatternError::InvalidChar { found: 'X' }
+        );
+    }
+
+    #[test]
+    fn game_constraints_summarize_every_row() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        game.submit_guess("chair").unwrap();
+        let constraints = game.constraints();
+        assert!(constraints.matches("CIGAR"));
+        // CHAIR showed a green C, so a word without it is out.
+        assert!(!constraints.matches("SUGAR"));
+
+        let secrets = crate::secret_words().iter().map(String::as_str);
+        assert_eq!(
+            constraints.filter_words(secrets),
+            crate::remaining_secrets(&game)
+        );
+    }
+
+    #[test]
+    fn game_constraints_agree_with_remaining_secrets() {
+        // Each game repeats a green across rows, and most mix in duplicate letters.
+        let games: [(&str, &[&str]); 6] = [
+            ("issue", &["crane", "cycle", "level"]),
+            ("sissy", &["salsa", "sassy", "missy"]),
+            ("llama", &["lolly", "label", "lilac"]),
+            ("eerie", &["geese", "emcee", "eerie"]),
+            ("apple", &["allot", "ample", "apply"]),
+            ("tenet", &["tweet", "treat", "tenth"]),
+        ];
+        for (secret, guesses) in games {
+            let mut game = Wordle::new(secret).unwrap();
+            for guess in guesses {
+                game.submit_guess(guess).unwrap();
+                let secrets = crate::secret_words().iter().map(String::as_str);
+                assert_eq!(
+                    game.constraints().filter_words(secrets),
+                    crate::remaining_secrets(&game),
+                    "{secret} after {guess}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gray_duplicates_cap_the_letter_count() {
+        let constraints = Constraints::new().require_green(2, 'L').forbid('L');
+        assert!(!constraints.matches("ALLOY"));
+        assert!(constraints.matches("COLON"));
+        assert!(!constraints.matches("COLO"));
+    }
+
+    #[test]
+    fn repeated_rows_do_not_raise_the_cap() {
+        let once = Constraints::new().observe("LLAMA", "GBBBB").unwrap();
+        let twice = once.clone().observe("LLAMA", "GBBBB").unwrap();
+        assert_eq!(once, twice);
+        assert!(!once.matches("LOLOO"));
+        assert!(!twice.matches("LOLOO"));
+        assert!(twice.matches("LOOPY"));
+    }
+}
@@ -1,77 +1,297 @@
+use fibble_core::{
+    compute_pattern_digits, encode_pattern, entropy_bits, try_compute_pattern_digits,
+    weighted_entropy_bits, ScoreError, PATTERN_ABSENT, PATTERN_CORRECT, PATTERN_PRESENT,
+};
 use once_cell::sync::Lazy;
 use rand::{thread_rng, Rng};
-use std::collections::HashSet;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-/// The fixed Wordle word length.
+mod constraints;
+mod multi_wordle;
+mod opening_book;
+mod pattern_matrix;
+mod search;
+mod solver;
+mod word;
+mod word_list;
+
+pub use constraints::Constraints;
+pub use fibble_core::{pattern_code_to_string, pattern_space};
+pub use multi_wordle::{MultiWordle, QUORDLE_BOARDS};
+pub use opening_book::{
+    cache_dir_path, OpeningBook, CACHE_DIR_ENV, OPENING_BOOK_FILE, OPENING_BOOK_VERSION,
+};
+pub use pattern_matrix::{
+    analyze_all_guesses, analyze_with_matrix, for_each_guess_analysis, PatternMatrix,
+};
+pub use search::{
+    best_two_ply_guess, optimal_remaining_guesses, OPTIMAL_SEARCH_LIMIT, TWO_PLY_FIRST_GUESSES,
+};
+pub use solver::{
+    hardest_secrets, secret_difficulty, simulate_all, solve, SolveStats, SOLVER_OPENER,
+};
+pub use word::{analyze_word_against, Word};
+pub use word_list::{validate_lists, ListIssue, ListKind, WordList};
+
+/// The standard Wordle word length, used by [`Wordle`] and the solver functions.
 pub const WORD_LENGTH: usize = 5;
-const ALPHABET_SIZE: usize = 26;
-const PATTERN_SPACE: usize = 3usize.pow(WORD_LENGTH as u32);
-const PATTERN_ABSENT: u8 = 0;
-const PATTERN_PRESENT: u8 = 1;
-const PATTERN_CORRECT: u8 = 2;
-
-static WORDLE_ALLOWED_LIST: Lazy<Vec<String>> = Lazy::new(|| {
-    include_str!("../data/wordle_allowed.txt")
-        .lines()
-        .filter_map(|line| {
-            let word = line.trim();
-            if word.is_empty() {
-                return None;
-            }
-
-            if word.chars().count() == WORD_LENGTH {
+const PATTERN_SPACE: usize = pattern_space(WORD_LENGTH);
+const DEFAULT_FIBBLE_LIES: usize = 1;
+const WORDLE_MAX_ATTEMPTS: usize = 6;
+const FIBBLE_MAX_ATTEMPTS: usize = 9;
+const ABSURDLE_MAX_ATTEMPTS: usize = 20;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The day of daily puzzle `0`, 2021-06-19 (Wordle's first puzzle), counted in days since the
+/// Unix epoch.
+pub const DAILY_EPOCH_DAYS: u64 = 18_797;
+
+static WORDLE_ALLOWED_LISTS: Lazy<HashMap<usize, Vec<String>>> = Lazy::new(|| {
+    group_by_length(
+        include_str!("../data/wordle_allowed.txt")
+            .lines()
+            .filter_map(|line| {
+                let word = line.trim();
+                if word.is_empty() {
+                    return None;
+                }
+
                 Some(word.to_ascii_uppercase())
-            } else {
-                None
-            }
-        })
-        .collect()
+            }),
+    )
 });
 
 static WORDLE_ALLOWED_SET: Lazy<HashSet<String>> =
-    Lazy::new(|| WORDLE_ALLOWED_LIST.iter().cloned().collect());
+    Lazy::new(|| WORDLE_ALLOWED_LISTS.values().flatten().cloned().collect());
 
-static WORDLE_SECRET_LIST: Lazy<Vec<String>> = Lazy::new(|| {
-    include_str!("../data/wordle_secrets.txt")
-        .lines()
-        .filter_map(|line| {
-            let word = line.trim();
-            if word.is_empty() {
-                return None;
-            }
+static WORD_LISTS_HASH: Lazy<u64> =
+    Lazy::new(|| word_lists_fingerprint(allowed_words(), secret_words()));
 
-            if word.chars().count() == WORD_LENGTH {
-                if !word.chars().all(|ch| ch.is_ascii_alphabetic()) {
-                    return None;
-                }
-                let uppercase = word.to_ascii_uppercase();
-                if !WORDLE_ALLOWED_SET.contains(&uppercase) {
-                    panic!("secret word {word} missing from allowed list");
-                }
-                Some(uppercase)
-            } else {
-                None
-            }
-        })
-        .collect()
+static WORDLE_SECRET_LISTS: Lazy<SecretLists> = Lazy::new(|| {
+    load_secret_lists(
+        include_str!("../data/wordle_secrets.txt"),
+        &WORDLE_ALLOWED_SET,
+    )
 });
 
+/// The bundled secrets grouped by length, plus any that the allowed list lacks.
+struct SecretLists {
+    by_length: HashMap<usize, Vec<String>>,
+    missing_from_allowed: Vec<String>,
+}
+
+/// Reads secret words from `text`, skipping blank and non-alphabetic lines. Secrets that are
+/// not in `allowed` could never be guessed, so they are left out and recorded instead.
+fn load_secret_lists(text: &str, allowed: &HashSet<String>) -> SecretLists {
+    let mut missing_from_allowed = Vec::new();
+    let by_length = group_by_length(text.lines().filter_map(|line| {
+        let word = line.trim();
+        if word.is_empty() || !word.chars().all(|ch| ch.is_ascii_alphabetic()) {
+            return None;
+        }
+        let uppercase = word.to_ascii_uppercase();
+        if !allowed.contains(&uppercase) {
+            missing_from_allowed.push(uppercase);
+            return None;
+        }
+        Some(uppercase)
+    }));
+    SecretLists {
+        by_length,
+        missing_from_allowed,
+    }
+}
+
+impl SecretLists {
+    fn of_length(&self, word_length: usize) -> &[String] {
+        self.by_length
+            .get(&word_length)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    fn check(&self) -> Result<(), DataError> {
+        if self.missing_from_allowed.is_empty() {
+            Ok(())
+        } else {
+            Err(DataError::SecretsNotAllowed {
+                words: self.missing_from_allowed.clone(),
+            })
+        }
+    }
+}
+
+fn group_by_length(words: impl Iterator<Item = String>) -> HashMap<usize, Vec<String>> {
+    let mut lists: HashMap<usize, Vec<String>> = HashMap::new();
+    for word in words {
+        lists.entry(word.chars().count()).or_default().push(word);
+    }
+    lists
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameMode {
     Wordle,
     Fibble,
+    /// The host never commits to a secret and answers each guess with the pattern that keeps
+    /// the most candidates alive.
+    Absurdle,
 }
 
-/// Represents a full Wordle game, keeping track of the secret word and guess history.
-#[derive(Debug, Clone)]
-pub struct Wordle {
+impl GameMode {
+    /// Returns how many guesses the mode allows: six for Wordle, nine for Fibble.
+    ///
+    /// Absurdle has no official limit, so it gets a generous cap of twenty.
+    pub fn max_attempts(self) -> usize {
+        match self {
+            GameMode::Wordle => WORDLE_MAX_ATTEMPTS,
+            GameMode::Fibble => FIBBLE_MAX_ATTEMPTS,
+            GameMode::Absurdle => ABSURDLE_MAX_ATTEMPTS,
+        }
+    }
+}
+
+impl FromStr for GameMode {
+    type Err = ModeParseError;
+
+    /// Parses `wordle`, `fibble`, or `absurdle`, ignoring case.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "wordle" => Ok(GameMode::Wordle),
+            "fibble" => Ok(GameMode::Fibble),
+            "absurdle" => Ok(GameMode::Absurdle),
+            _ => Err(ModeParseError {
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// The error returned when a string names no known [`GameMode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeParseError {
+    value: String,
+}
+
+impl fmt::Display for ModeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown mode: {}", self.value)
+    }
+}
+
+impl std::error::Error for ModeParseError {}
+
+/// Represents a full game on `N`-letter words, keeping track of the secret word and guess
+/// history.
+///
+/// The bundled word lists only contain five-letter words, which is what [`Wordle`] plays.
+///
+/// With the `serde` feature, games serialize their secret, mode, lie count, and full history.
+/// Deserializing re-validates every word against the bundled list, so games created with
+/// [`WordleN::new_with_lists`] do not round-trip.
+///
+/// In [`GameMode::Absurdle`] the secret is only provisional: every guess narrows the host's
+/// candidate set and the secret moves to a word that stays consistent with all feedback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SavedGame"))]
+pub struct WordleN<const N: usize> {
     secret: String,
     mode: GameMode,
+    lies: usize,
+    lies_optional: bool,
+    hard: bool,
+    reject_repeats: bool,
+    allow_unknown: bool,
+    track_candidates: bool,
+    max_attempts: usize,
     guesses: Vec<GuessResult>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    allowed: Option<WordList>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    candidates: Vec<String>,
+    /// The secrets consistent with every row so far, kept when `track_candidates` is set.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tracked: Vec<&'static str>,
+}
+
+/// The unvalidated shape of a serialized game.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SavedGame {
+    secret: String,
+    mode: GameMode,
+    lies: usize,
+    #[serde(default)]
+    lies_optional: bool,
+    #[serde(default)]
+    hard: bool,
+    #[serde(default)]
+    reject_repeats: bool,
+    #[serde(default)]
+    allow_unknown: bool,
+    #[serde(default)]
+    track_candidates: bool,
+    #[serde(default)]
+    max_attempts: Option<usize>,
+    guesses: Vec<GuessResult>,
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> TryFrom<SavedGame> for WordleN<N> {
+    type Error = WordleError;
+
+    fn try_from(saved: SavedGame) -> Result<Self, Self::Error> {
+        if saved.lies > N {
+            return Err(WordleError::TooManyLies {
+                lies: saved.lies,
+                max: N,
+            });
+        }
+        let max_attempts = saved
+            .max_attempts
+            .unwrap_or_else(|| saved.mode.max_attempts());
+        let mut game = Self::from_history_limited(
+            &saved.secret,
+            saved.mode,
+            saved.guesses,
+            max_attempts,
+            saved.allow_unknown,
+        )?;
+        if saved.mode == GameMode::Fibble {
+            game.lies = saved.lies;
+            game.lies_optional = saved.lies_optional;
+        }
+        game.hard = saved.hard;
+        game.reject_repeats = saved.reject_repeats;
+        game.allow_unknown = saved.allow_unknown;
+        if saved.track_candidates {
+            game = game.tracking_candidates();
+        }
+        Ok(game)
+    }
+}
+
+/// A standard five-letter game.
+pub type Wordle = WordleN<WORD_LENGTH>;
+
+fn default_lies(mode: GameMode) -> usize {
+    match mode {
+        GameMode::Wordle | GameMode::Absurdle => 0,
+        GameMode::Fibble => DEFAULT_FIBBLE_LIES,
+    }
 }
 
-impl Wordle {
+impl<const N: usize> WordleN<N> {
     /// Creates a new game with the provided secret word (case-insensitive).
     pub fn new(secret: &str) -> Result<Self, WordleError> {
         Self::new_with_mode(secret, GameMode::Wordle)
@@ -79,35 +299,359 @@ impl Wordle {
 
     /// Creates a new game with a specific ruleset.
     pub fn new_with_mode(secret: &str, mode: GameMode) -> Result<Self, WordleError> {
-        let normalized = normalize(secret)?;
+        let normalized = normalize_length(secret, N)?;
         ensure_allowed(&normalized)?;
-        Ok(Self {
+        let mut game = Self {
             secret: normalized,
             mode,
+            lies: default_lies(mode),
+            lies_optional: false,
+            hard: false,
+            reject_repeats: false,
+            allow_unknown: false,
+            track_candidates: false,
+            max_attempts: mode.max_attempts(),
             guesses: Vec::new(),
-        })
+            allowed: None,
+            candidates: Vec::new(),
+            tracked: Vec::new(),
+        };
+        game.reset_candidates();
+        Ok(game)
+    }
+
+    /// Rebuilds a game from already-scored rows, for example from a saved session.
+    ///
+    /// Rows are kept as-is rather than re-scored, so Fibble lies are preserved, but each guess
+    /// is uppercased along with its tiles. The secret and every guessed word must be in the
+    /// allowed list, and the history may not exceed the mode's [`GameMode::max_attempts`].
+    pub fn from_history(
+        secret: &str,
+        mode: GameMode,
+        guesses: Vec<GuessResult>,
+    ) -> Result<Self, WordleError> {
+        Self::from_history_limited(secret, mode, guesses, mode.max_attempts(), false)
+    }
+
+    /// Like [`WordleN::from_history`], with a custom attempt limit and, when `allow_unknown`
+    /// is set, guesses outside the allowed list.
+    fn from_history_limited(
+        secret: &str,
+        mode: GameMode,
+        mut guesses: Vec<GuessResult>,
+        max_attempts: usize,
+        allow_unknown: bool,
+    ) -> Result<Self, WordleError> {
+        let mut game = Self::new_with_mode(secret, mode)?.with_max_attempts(max_attempts);
+        if guesses.len() > max_attempts {
+            return Err(WordleError::TooManyGuesses {
+                max: max_attempts,
+                found: guesses.len(),
+            });
+        }
+        for row in &mut guesses {
+            let normalized = normalize_length(row.guess(), N)?;
+            if !allow_unknown {
+                ensure_allowed(&normalized)?;
+            }
+            if row.letters().len() != N {
+                return Err(WordleError::InvalidLength {
+                    expected: N,
+                    found: row.letters().len(),
+                });
+            }
+            // Scoring only understands uppercase letters, so a hand-edited save is normalized
+            // here rather than trusted.
+            for (state, letter) in row.letters.iter_mut().zip(normalized.chars()) {
+                *state = match state {
+                    LetterState::Correct(_) => LetterState::Correct(letter),
+                    LetterState::Present(_) => LetterState::Present(letter),
+                    LetterState::Absent(_) => LetterState::Absent(letter),
+                };
+            }
+            row.guess = normalized;
+        }
+        game.guesses = guesses;
+        game.rebuild_host_candidates();
+        Ok(game)
+    }
+
+    /// Creates a Fibble game that lies on `lies` distinct tiles of every row.
+    ///
+    /// Errors if `lies` exceeds the word length.
+    pub fn new_fibble_with_lies(secret: &str, lies: usize) -> Result<Self, WordleError> {
+        if lies > N {
+            return Err(WordleError::TooManyLies { lies, max: N });
+        }
+        let mut game = Self::new_with_mode(secret, GameMode::Fibble)?;
+        game.lies = lies;
+        Ok(game)
+    }
+
+    /// Creates a Fibble variant whose host lies on *at most* `max_lies` tiles of every row.
+    ///
+    /// Each row draws its lie count uniformly from `0..=max_lies`, so a row may be entirely
+    /// truthful. Secret filtering accepts any count in that range, which keeps more secrets
+    /// alive than the exact-count game. Errors if `max_lies` exceeds the word length.
+    pub fn new_fibble_with_optional_lies(
+        secret: &str,
+        max_lies: usize,
+    ) -> Result<Self, WordleError> {
+        let mut game = Self::new_fibble_with_lies(secret, max_lies)?;
+        game.lies_optional = true;
+        Ok(game)
+    }
+
+    /// Creates a hard-mode game: every guess must keep earlier greens in place and reuse every
+    /// revealed yellow.
+    ///
+    /// Fibble rows may lie, so they could demand the impossible; hard mode is not enforced
+    /// in Fibble games.
+    pub fn new_hard_mode(secret: &str, mode: GameMode) -> Result<Self, WordleError> {
+        let mut game = Self::new_with_mode(secret, mode)?;
+        game.hard = true;
+        Ok(game)
+    }
+
+    /// Overrides the mode's attempt limit. Pass `usize::MAX` for an endless practice game; a
+    /// limit of `0` leaves the game over before it starts.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Makes later guesses of an already-played word fail with
+    /// [`WordleError::RepeatedGuess`] instead of spending an attempt.
+    pub fn rejecting_repeats(mut self) -> Self {
+        self.reject_repeats = true;
+        self
+    }
+
+    /// Accepts any guess with `N` ASCII letters, whether or not it is in the allowed list, for
+    /// experimental play. The secret was still validated when the game was created.
+    pub fn allowing_unknown_guesses(mut self) -> Self {
+        self.allow_unknown = true;
+        self
+    }
+
+    /// Keeps the remaining secrets up to date as guesses are submitted, so
+    /// [`remaining_secrets`] and friends read them instead of re-checking every secret against
+    /// the whole history on each call.
+    ///
+    /// Each new row only filters the current survivors. That is exact in every mode, Fibble
+    /// included: a secret survives when each row, on its own, shows the permitted number of
+    /// lies against it, so a later row can never bring back a secret an earlier one ruled out.
+    /// [`WordleN::undo_last_guess`] widens the set again by recomputing it.
+    pub fn tracking_candidates(mut self) -> Self {
+        self.track_candidates = true;
+        self.rebuild_tracked();
+        self
+    }
+
+    /// Creates a new game that validates the secret and every guess against `allowed`
+    /// instead of the bundled list.
+    ///
+    /// [`remaining_secrets`] still filters the bundled secrets; use
+    /// [`remaining_secrets_from`] to narrow a custom pool.
+    pub fn new_with_lists(
+        secret: &str,
+        mode: GameMode,
+        allowed: &WordList,
+    ) -> Result<Self, WordleError> {
+        let mut game = Self {
+            secret: String::new(),
+            mode,
+            lies: default_lies(mode),
+            lies_optional: false,
+            hard: false,
+            reject_repeats: false,
+            allow_unknown: false,
+            track_candidates: false,
+            max_attempts: mode.max_attempts(),
+            guesses: Vec::new(),
+            allowed: Some(allowed.clone()),
+            candidates: Vec::new(),
+            tracked: Vec::new(),
+        };
+        let normalized = normalize_length(secret, N)?;
+        game.ensure_allowed(&normalized)?;
+        game.secret = normalized;
+        game.reset_candidates();
+        Ok(game)
     }
 
     /// Records a guess, returning the scored row so callers can inspect or display it.
     pub fn submit_guess(&mut self, guess: &str) -> Result<&GuessResult, WordleError> {
-        let normalized_guess = normalize(guess)?;
-        ensure_allowed(&normalized_guess)?;
-        let mut letters = score(&self.secret, &normalized_guess);
-        if matches!(self.mode, GameMode::Fibble) {
-            apply_fibble_lie(&mut letters);
+        self.submit_guess_with_rng(guess, &mut thread_rng())
+    }
+
+    /// Like [`WordleN::submit_guess`], but draws Fibble lies from `rng` so that a seeded
+    /// generator reproduces the same lies.
+    pub fn submit_guess_with_rng(
+        &mut self,
+        guess: &str,
+        rng: &mut impl Rng,
+    ) -> Result<&GuessResult, WordleError> {
+        self.ensure_accepting_guesses()?;
+        let normalized_guess = normalize_length(guess, N)?;
+        if !self.allow_unknown {
+            self.ensure_allowed(&normalized_guess)?;
+        }
+        if self.reject_repeats && self.guesses.iter().any(|row| row.guess == normalized_guess) {
+            return Err(WordleError::RepeatedGuess {
+                word: normalized_guess,
+            });
+        }
+        if self.hard
+            && self.mode != GameMode::Fibble
+            && let Some(reason) = hard_mode_violation(&self.guesses, &normalized_guess)
+        {
+            return Err(WordleError::HardModeViolation { reason });
         }
+        if self.mode == GameMode::Absurdle {
+            self.narrow_candidates(&normalized_guess);
+        }
+        let mut letters = try_score_n::<N>(&self.secret, &normalized_guess)?;
+        let lie_indices = if matches!(self.mode, GameMode::Fibble) {
+            let count = if self.lies_optional {
+                rng.gen_range(0..=self.lies)
+            } else {
+                self.lies
+            };
+            apply_fibble_lies(&mut letters, count, rng)
+        } else {
+            Vec::new()
+        };
         self.guesses.push(GuessResult {
             guess: normalized_guess,
             letters,
+            lie_indices,
         });
+        let row = self.guesses.last().expect("just pushed");
+        if self.track_candidates {
+            let mut tracked = std::mem::take(&mut self.tracked);
+            tracked.retain(|secret| secret_matches_row(secret, row, self));
+            self.tracked = tracked;
+        }
         Ok(self.guesses.last().expect("just pushed"))
     }
 
+    /// Recomputes the tracked secrets from the full list, when tracking is on.
+    fn rebuild_tracked(&mut self) {
+        if !self.track_candidates {
+            return;
+        }
+        self.tracked = secret_words_of_length(N)
+            .iter()
+            .map(String::as_str)
+            .filter(|secret| secret_matches_history(secret, self))
+            .collect();
+    }
+
+    /// Returns the range of lied tiles a row may have: `0` outside Fibble.
+    fn lie_range(&self) -> RangeInclusive<usize> {
+        if self.lies_optional {
+            0..=self.lies
+        } else {
+            self.lies..=self.lies
+        }
+    }
+
+    /// Errors with [`WordleError::AlreadySolved`] or [`WordleError::OutOfAttempts`] once the
+    /// game is over.
+    fn ensure_accepting_guesses(&self) -> Result<(), WordleError> {
+        if self.is_solved() {
+            Err(WordleError::AlreadySolved)
+        } else if self.guesses.len() >= self.max_attempts {
+            Err(WordleError::OutOfAttempts {
+                max: self.max_attempts,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Seeds the Absurdle host's candidates from the secret list (or the custom list).
+    fn reset_candidates(&mut self) {
+        if self.mode != GameMode::Absurdle {
+            return;
+        }
+        self.candidates = match &self.allowed {
+            Some(list) => list.words().to_vec(),
+            None => secret_words_of_length(N).to_vec(),
+        };
+    }
+
+    /// Resets the Absurdle host's candidates to those consistent with the current history.
+    fn rebuild_host_candidates(&mut self) {
+        if self.mode != GameMode::Absurdle {
+            return;
+        }
+        self.reset_candidates();
+        let candidates = std::mem::take(&mut self.candidates);
+        self.candidates = candidates
+            .into_iter()
+            .filter(|word| secret_matches_history(word, self))
+            .collect();
+    }
+
+    /// Keeps the largest bucket of candidates `guess` could split them into and moves the
+    /// secret into it. Ties avoid the all-correct bucket, then prefer the lowest pattern code.
+    fn narrow_candidates(&mut self, guess: &str) {
+        let all_correct = pattern_space(N) - 1;
+        let mut buckets: HashMap<usize, Vec<String>> = HashMap::new();
+        for word in self.candidates.drain(..) {
+            let code = encode_pattern(&compute_pattern_digits::<N>(
+                word.as_bytes(),
+                guess.as_bytes(),
+            ));
+            buckets.entry(code).or_default().push(word);
+        }
+        let largest = buckets.into_iter().max_by(|(a_code, a), (b_code, b)| {
+            a.len()
+                .cmp(&b.len())
+                .then((*a_code != all_correct).cmp(&(*b_code != all_correct)))
+                .then(b_code.cmp(a_code))
+        });
+        if let Some((_, bucket)) = largest {
+            self.secret = bucket[0].clone();
+            self.candidates = bucket;
+        }
+    }
+
+    fn ensure_allowed(&self, word: &str) -> Result<(), WordleError> {
+        match &self.allowed {
+            Some(list) if list.contains(word) => Ok(()),
+            Some(_) => Err(WordleError::UnknownWord {
+                word: word.to_string(),
+            }),
+            None => ensure_allowed(word),
+        }
+    }
+
     /// Returns the guesses made so far, in submission order.
     pub fn guesses(&self) -> &[GuessResult] {
         &self.guesses
     }
 
+    /// Folds every row into the best-known state per letter, as a keyboard display shows it.
+    ///
+    /// `Correct` beats `Present`, which beats `Absent`, so a letter that was gray in one slot
+    /// but yellow in another reports `Present`. Fibble rows are folded as shown, so the result
+    /// may reflect lied feedback.
+    pub fn letter_knowledge(&self) -> HashMap<char, LetterState> {
+        let mut knowledge: HashMap<char, LetterState> = HashMap::new();
+        for state in self.guesses.iter().flat_map(|row| &row.letters) {
+            let known = knowledge
+                .entry(state.letter())
+                .or_insert_with(|| state.clone());
+            if state.rank() > known.rank() {
+                *known = state.clone();
+            }
+        }
+        knowledge
+    }
+
     /// Returns the hidden solution word in its normalized (uppercase) form.
     pub fn secret(&self) -> &str {
         &self.secret
@@ -117,447 +661,3738 @@ impl Wordle {
     pub fn mode(&self) -> GameMode {
         self.mode
     }
-}
 
-/// The per-letter states emitted by Wordle scoring.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum LetterState {
-    Correct(char),
-    Present(char),
-    Absent(char),
-}
+    /// Returns the secrets the Absurdle host can still commit to; empty in other modes.
+    pub fn host_candidates(&self) -> &[String] {
+        &self.candidates
+    }
 
-impl LetterState {
-    /// Returns the uppercase character that originated this state.
-    pub fn letter(&self) -> char {
-        match self {
-            LetterState::Correct(c) | LetterState::Present(c) | LetterState::Absent(c) => *c,
+    /// Removes and returns the most recent row, or `None` if nothing has been guessed.
+    ///
+    /// The game reopens if that row ended it. Fibble rows are removed with their lies as
+    /// shown; the Absurdle host regains every secret consistent with the remaining rows but
+    /// keeps its current secret, which is always one of them.
+    pub fn undo_last_guess(&mut self) -> Option<GuessResult> {
+        let row = self.guesses.pop()?;
+        self.rebuild_host_candidates();
+        self.rebuild_tracked();
+        Some(row)
+    }
+
+    /// Like [`WordleN::submit_guess`], but also reports whether the guess ended the game.
+    pub fn submit_and_check(
+        &mut self,
+        guess: &str,
+    ) -> Result<(&GuessResult, Outcome), WordleError> {
+        self.submit_guess(guess)?;
+        let outcome = self.outcome();
+        Ok((self.guesses.last().expect("just pushed"), outcome))
+    }
+
+    /// Returns where the game stands: still going, won, or lost.
+    pub fn outcome(&self) -> Outcome {
+        if let Some(idx) = self.guesses.iter().position(|row| row.guess == self.secret) {
+            Outcome::Won { attempts: idx + 1 }
+        } else if self.is_over() {
+            Outcome::Lost {
+                secret: self.secret.clone(),
+            }
+        } else {
+            Outcome::Ongoing
         }
     }
 
-    fn color_code(&self) -> &'static str {
-        match self {
-            LetterState::Correct(_) => "\x1b[48;5;34m\x1b[97m", // green background, bright text
-            LetterState::Present(_) => "\x1b[48;5;178m\x1b[30m", // yellow background, dark text
-            LetterState::Absent(_) => "\x1b[48;5;240m\x1b[97m", // gray background, bright text
+    /// Returns how many guesses the game allows in total.
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Whether the secret has been guessed, even if Fibble lied about the winning row.
+    pub fn is_solved(&self) -> bool {
+        self.guesses.iter().any(|row| row.guess == self.secret)
+    }
+
+    /// Whether the game has ended, either solved or out of attempts.
+    pub fn is_over(&self) -> bool {
+        self.is_solved() || self.guesses.len() >= self.max_attempts
+    }
+
+    /// Returns how many more guesses may be submitted; `0` once the game is over.
+    pub fn remaining_attempts(&self) -> usize {
+        if self.is_over() {
+            0
+        } else {
+            self.max_attempts - self.guesses.len()
         }
     }
 
-    fn colored_block(&self) -> String {
-        format!("{} {} \x1b[0m", self.color_code(), self.letter())
+    /// Whether guesses must reuse every hint revealed so far.
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard
     }
-}
 
-/// A scored guess row including letter-by-letter states.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GuessResult {
-    guess: String,
-    letters: Vec<LetterState>,
-}
+    /// Whether replaying an earlier guess is an error; see [`WordleN::rejecting_repeats`].
+    pub fn rejects_repeats(&self) -> bool {
+        self.reject_repeats
+    }
 
-impl GuessResult {
-    /// Returns the normalized (uppercase) guess string.
-    pub fn guess(&self) -> &str {
-        &self.guess
+    /// Whether guesses may be words outside the allowed list; see
+    /// [`WordleN::allowing_unknown_guesses`].
+    pub fn allows_unknown_guesses(&self) -> bool {
+        self.allow_unknown
     }
 
-    /// Returns the per-letter feedback for this guess.
-    pub fn letters(&self) -> &[LetterState] {
-        &self.letters
+    /// Returns how many tiles of each row lie: `0` in Wordle mode and `1` in standard Fibble.
+    pub fn lies_per_guess(&self) -> usize {
+        self.lies
     }
 
-    /// Whether the guess matched the secret completely.
-    pub fn is_correct(&self) -> bool {
-        self.letters
-            .iter()
-            .all(|state| matches!(state, LetterState::Correct(_)))
+    /// Whether [`WordleN::lies_per_guess`] is an upper bound rather than an exact count.
+    pub fn lies_are_optional(&self) -> bool {
+        self.lies_optional
     }
 
-    /// Converts the scored row into a colored string ready for terminal output.
-    pub fn colored_string(&self) -> String {
-        self.letters
-            .iter()
-            .map(LetterState::colored_block)
-            .collect::<Vec<_>>()
-            .join(" ")
+    /// Renders the spoiler-free emoji grid players paste to share a result.
+    ///
+    /// The header reads like `Wordle 4/6`, with `X` in place of the count when the secret was
+    /// not guessed. The limit is the game's own, so `--max-attempts 10` shows `/10` and an
+    /// endless game shows `/∞`. `dark_mode` picks `⬛` for absent tiles; otherwise `⬜` is used.
+    pub fn share_text(&self, dark_mode: bool) -> String {
+        let name = match self.mode {
+            GameMode::Wordle => "Wordle",
+            GameMode::Fibble => "Fibble",
+            GameMode::Absurdle => "Absurdle",
+        };
+        let attempts = match self.guesses.iter().position(|row| row.guess == self.secret) {
+            Some(idx) => (idx + 1).to_string(),
+            None => String::from("X"),
+        };
+        let limit = match self.max_attempts {
+            usize::MAX => String::from("∞"),
+            max => max.to_string(),
+        };
+        let mut text = format!("{name} {attempts}/{limit}");
+        for row in &self.guesses {
+            text.push('\n');
+            text.push_str(&row.emoji_string(dark_mode));
+        }
+        text
     }
-}
 
-impl fmt::Display for GuessResult {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.colored_string())
+    /// Renders every row in color, one per line, padded with blank rows up to
+    /// [`WordleN::max_attempts`] so the board keeps its shape as it fills.
+    ///
+    /// Endless games (`usize::MAX` attempts) get no blank rows. There is no trailing newline.
+    pub fn render_board(&self) -> String {
+        self.render_board_with_theme(&Theme::default())
     }
-}
 
-/// Summary information about a guess evaluated against every possible secret word.
-#[derive(Debug, Clone)]
-pub struct GuessEntropy {
-    guess: String,
-    pattern_counts: [usize; PATTERN_SPACE],
-}
+    /// Like [`WordleN::render_board`], painting tiles with `theme` instead.
+    pub fn render_board_with_theme(&self, theme: &Theme) -> String {
+        self.render_board_rows(|row| row.colored_string_with_theme(theme), " _ ")
+    }
 
-impl GuessEntropy {
-    /// Returns the normalized (uppercase) guess word.
-    pub fn guess(&self) -> &str {
-        &self.guess
+    /// Like [`WordleN::render_board`], but with [`GuessResult::plain_string`] rows and `_`
+    /// for the tiles of unused rows.
+    pub fn render_board_plain(&self) -> String {
+        self.render_board_rows(GuessResult::plain_string, "_")
     }
 
-    /// Returns the number of secrets considered when computing the entropy.
-    pub fn total_secrets(&self) -> usize {
-        self.pattern_counts.iter().sum()
+    /// Renders the played rows as an HTML `<table>` with one cell per tile, colored inline so
+    /// it needs no stylesheet when pasted into a page.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(
+            "<table class=\"fibble-board\" style=\"border-collapse:separate;border-spacing:4px\">\n",
+        );
+        for row in &self.guesses {
+            html.push_str("<tr>");
+            for state in &row.letters {
+                html.push_str(&format!(
+                    "<td style=\"background:{};color:#fff;width:2em;height:2em;\
+                     text-align:center;font:bold 1.2em sans-serif\">{}</td>",
+                    state.hex_color(),
+                    escape_markup(state.letter())
+                ));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>");
+        html
     }
 
-    /// Returns each color pattern and how many secrets yield it.
-    pub fn pattern_counts(&self) -> Vec<(String, usize)> {
-        self.pattern_counts
-            .iter()
-            .enumerate()
-            .filter(|(_, count)| **count > 0)
-            .map(|(code, count)| (pattern_code_to_string(code), *count))
-            .collect()
+    /// Renders the played rows as a standalone SVG image of 40-pixel tiles.
+    pub fn to_svg(&self) -> String {
+        const TILE: usize = 40;
+        const GAP: usize = 4;
+        let width = N * (TILE + GAP) + GAP;
+        let height = self.guesses.len() * (TILE + GAP) + GAP;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\" font-weight=\"bold\" \
+             font-size=\"24\" text-anchor=\"middle\">\n"
+        );
+        for (row_idx, row) in self.guesses.iter().enumerate() {
+            let y = GAP + row_idx * (TILE + GAP);
+            for (col, state) in row.letters.iter().enumerate() {
+                let x = GAP + col * (TILE + GAP);
+                svg.push_str(&format!(
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{TILE}\" height=\"{TILE}\" fill=\"{}\"/>\
+                     <text x=\"{}\" y=\"{}\" fill=\"#fff\">{}</text>\n",
+                    state.hex_color(),
+                    x + TILE / 2,
+                    y + TILE / 2 + 8,
+                    escape_markup(state.letter())
+                ));
+            }
+        }
+        svg.push_str("</svg>");
+        svg
     }
 
-    /// Returns how many distinct patterns were observed.
-    pub fn distinct_patterns(&self) -> usize {
-        self.pattern_counts
+    fn render_board_rows(&self, render: impl Fn(&GuessResult) -> String, blank: &str) -> String {
+        let blank_rows = if self.max_attempts == usize::MAX {
+            0
+        } else {
+            self.max_attempts.saturating_sub(self.guesses.len())
+        };
+        let blank_row = vec![blank; N].join(" ");
+        self.guesses
             .iter()
-            .filter(|count| **count > 0)
-            .count()
+            .map(render)
+            .chain(std::iter::repeat_n(blank_row, blank_rows))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
-    /// Computes the Shannon entropy (in bits) of the pattern distribution.
-    pub fn entropy_bits(&self) -> f64 {
-        let total = self.total_secrets() as f64;
-        self.pattern_counts.iter().fold(0.0, |acc, count| {
-            if *count == 0 {
-                acc
-            } else {
-                let probability = *count as f64 / total;
-                acc - probability * probability.log2()
-            }
-        })
+    /// Snapshots the game into an immutable record suitable for a leaderboard.
+    pub fn to_record(&self, duration: Duration) -> GameRecord {
+        GameRecord {
+            mode: self.mode,
+            secret: self.secret.clone(),
+            guesses: self
+                .guesses
+                .iter()
+                .map(|row| (row.guess.clone(), row.pattern_string()))
+                .collect(),
+            solved: self.is_solved(),
+            attempts: self.guesses.len(),
+            duration,
+        }
     }
 }
 
-/// Errors that can occur while creating a game or submitting guesses.
+/// Where a game stands after a guess.
+///
+/// A Fibble game is won when the secret is actually guessed, even if the displayed row lied.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum WordleError {
-    InvalidLength { expected: usize, found: usize },
-    UnknownWord { word: String },
+pub enum Outcome {
+    Ongoing,
+    Won { attempts: usize },
+    Lost { secret: String },
 }
 
-impl fmt::Display for WordleError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            WordleError::InvalidLength { expected, found } => write!(
-                f,
-                "expected a {expected}-letter word, but found {found} letters"
-            ),
-            WordleError::UnknownWord { .. } => write!(f, "that word is not in the Wordle list"),
+/// An immutable summary of a played game, as stored by leaderboards.
+///
+/// Each guess is paired with the `G`/`Y`/`B` pattern the player was shown. `solved` reflects
+/// whether the secret was actually guessed, even when Fibble lied about the winning row.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameRecord {
+    mode: GameMode,
+    secret: String,
+    guesses: Vec<(String, String)>,
+    solved: bool,
+    attempts: usize,
+    duration: Duration,
+}
+
+impl GameRecord {
+    /// Returns the ruleset the game was played under.
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    /// Returns the normalized (uppercase) secret word.
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Returns each guess alongside the pattern string shown for it.
+    pub fn guesses(&self) -> &[(String, String)] {
+        &self.guesses
+    }
+
+    /// Whether the secret was guessed.
+    pub fn solved(&self) -> bool {
+        self.solved
+    }
+
+    /// Returns how many guesses were submitted.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// Returns how long the game took.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// The per-letter states emitted by Wordle scoring.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LetterState {
+    Correct(char),
+    Present(char),
+    Absent(char),
+}
+
+impl LetterState {
+    /// Returns the uppercase character that originated this state.
+    pub fn letter(&self) -> char {
+        match self {
+            LetterState::Correct(c) | LetterState::Present(c) | LetterState::Absent(c) => *c,
+        }
+    }
+
+    /// Whether the letter is in the right spot.
+    pub fn is_correct(&self) -> bool {
+        matches!(self, LetterState::Correct(_))
+    }
+
+    /// Whether the letter is in the word but in a different spot.
+    pub fn is_present(&self) -> bool {
+        matches!(self, LetterState::Present(_))
+    }
+
+    /// Whether the letter is not in the word (or all its copies are already accounted for).
+    pub fn is_absent(&self) -> bool {
+        matches!(self, LetterState::Absent(_))
+    }
+
+    /// The CSS background color for the tile in HTML and SVG exports.
+    fn hex_color(&self) -> &'static str {
+        match self {
+            LetterState::Correct(_) => "#6aaa64",
+            LetterState::Present(_) => "#c9b458",
+            LetterState::Absent(_) => "#787c7e",
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            LetterState::Correct(_) => 2,
+            LetterState::Present(_) => 1,
+            LetterState::Absent(_) => 0,
+        }
+    }
+
+    fn colored_block(&self, code: &str) -> String {
+        format!("{code} {} \x1b[0m", self.letter())
+    }
+
+    fn plain_block(&self) -> String {
+        match self {
+            LetterState::Correct(c) => format!("[{c}]"),
+            LetterState::Present(c) => format!("({c})"),
+            LetterState::Absent(c) => c.to_ascii_lowercase().to_string(),
         }
     }
 }
 
-impl std::error::Error for WordleError {}
+/// Writes the bare letter; use [`GuessResult`]'s renderers for colored tiles.
+impl fmt::Display for LetterState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
 
-fn normalize(word: &str) -> Result<String, WordleError> {
-    let len = word.chars().count();
-    if len != WORD_LENGTH {
-        return Err(WordleError::InvalidLength {
-            expected: WORD_LENGTH,
-            found: len,
-        });
+/// Escapes a tile's letter for HTML or SVG text. Game letters are always A-Z; this keeps the
+/// markup well-formed regardless.
+fn escape_markup(letter: char) -> String {
+    match letter {
+        '&' => String::from("&amp;"),
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        '"' => String::from("&quot;"),
+        '\'' => String::from("&#39;"),
+        other => other.to_string(),
     }
+}
 
-    Ok(word.to_ascii_uppercase())
+/// The ANSI escape sequences used to paint each tile state in the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub correct: String,
+    pub present: String,
+    pub absent: String,
+    /// Paints absent tiles whose letter is in the word after all, because the guess used it
+    /// more times than the secret has (see [`GuessResult::is_extra_copy`]). `None` paints them
+    /// like any other absent tile.
+    pub extra_copy: Option<String>,
 }
 
-fn ensure_allowed(word: &str) -> Result<(), WordleError> {
-    if WORDLE_ALLOWED_SET.contains(word) {
-        Ok(())
-    } else {
-        Err(WordleError::UnknownWord {
-            word: word.to_string(),
+impl Theme {
+    /// Marks extra copies of a letter in dark yellow instead of gray, which helps players
+    /// still learning how repeated letters are scored.
+    pub fn marking_extra_copies(mut self) -> Self {
+        self.extra_copy = Some(String::from("\x1b[48;5;136m\x1b[97m")); // dark yellow, bright text
+        self
+    }
+
+    /// The colorblind-friendly palette: orange for correct and blue for present tiles.
+    pub fn high_contrast() -> Self {
+        Self {
+            correct: String::from("\x1b[48;5;208m\x1b[30m"), // orange background, dark text
+            present: String::from("\x1b[48;5;75m\x1b[30m"),  // blue background, dark text
+            absent: String::from("\x1b[48;5;240m\x1b[97m"),  // gray background, bright text
+            extra_copy: None,
+        }
+    }
+
+    fn color_code(&self, state: &LetterState) -> &str {
+        match state {
+            LetterState::Correct(_) => &self.correct,
+            LetterState::Present(_) => &self.present,
+            LetterState::Absent(_) => &self.absent,
+        }
+    }
+}
+
+impl Default for Theme {
+    /// The classic green, yellow, and gray palette.
+    fn default() -> Self {
+        Self {
+            correct: String::from("\x1b[48;5;34m\x1b[97m"), // green background, bright text
+            present: String::from("\x1b[48;5;178m\x1b[30m"), // yellow background, dark text
+            absent: String::from("\x1b[48;5;240m\x1b[97m"), // gray background, bright text
+            extra_copy: None,
+        }
+    }
+}
+
+/// The state of one tile while a guess is still being typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialTile {
+    /// Nothing has been typed in this slot yet.
+    Empty,
+    /// An uppercase A-Z letter.
+    Letter(char),
+    /// A typed character that can never appear in a Wordle word.
+    Invalid(char),
+}
+
+/// Checks partially typed input so a UI can highlight it before the guess is submitted.
+///
+/// No secret is involved, so this reveals nothing about the answer: each tile only reports
+/// whether a letter was typed and whether it is A-Z. The result always has `WORD_LENGTH`
+/// tiles, padded with `Empty`; characters typed past the last slot are ignored.
+pub fn check_partial(partial: &str) -> Vec<PartialTile> {
+    let mut tiles = vec![PartialTile::Empty; WORD_LENGTH];
+    for (tile, ch) in tiles.iter_mut().zip(partial.chars()) {
+        *tile = if ch.is_ascii_alphabetic() {
+            PartialTile::Letter(ch.to_ascii_uppercase())
+        } else {
+            PartialTile::Invalid(ch)
+        };
+    }
+    tiles
+}
+
+/// A scored guess row including letter-by-letter states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GuessResult {
+    guess: String,
+    letters: Vec<LetterState>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    lie_indices: Vec<usize>,
+}
+
+impl GuessResult {
+    /// Builds a row from a guess and the `G`/`Y`/`B` pattern it showed, for games played
+    /// elsewhere. The guess need not be an allowed word. Which tiles lied is not known, so
+    /// [`GuessResult::lie_indices`] is empty.
+    pub fn from_pattern(guess: &str, pattern: &str) -> Result<Self, WordleError> {
+        let letters = letter_states(guess, pattern)?;
+        Ok(Self {
+            guess: letters.iter().map(LetterState::letter).collect(),
+            letters,
+            lie_indices: Vec::new(),
         })
     }
+
+    /// Returns the normalized (uppercase) guess string.
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    /// Returns the per-letter feedback for this guess.
+    pub fn letters(&self) -> &[LetterState] {
+        &self.letters
+    }
+
+    /// Returns the position whose feedback was falsified, for Fibble rows.
+    ///
+    /// Always `None` in plain Wordle mode. Rows with several lies report the lowest position;
+    /// see [`GuessResult::lie_indices`] for all of them.
+    pub fn lie_index(&self) -> Option<usize> {
+        self.lie_indices.first().copied()
+    }
+
+    /// Returns every falsified position in ascending order; empty in plain Wordle mode.
+    pub fn lie_indices(&self) -> &[usize] {
+        &self.lie_indices
+    }
+
+    /// Whether the guess matched the secret completely.
+    pub fn is_correct(&self) -> bool {
+        self.letters.iter().all(LetterState::is_correct)
+    }
+
+    /// Returns the row's feedback as a base-3 pattern code, the encoding the entropy helpers
+    /// and [`pattern_code_to_string`] use.
+    ///
+    /// Fibble rows encode the displayed feedback, lies included, since that is what the
+    /// player saw.
+    pub fn pattern_code(&self) -> usize {
+        self.letters
+            .iter()
+            .fold(0usize, |acc, state| acc * 3 + state.rank() as usize)
+    }
+
+    /// Returns the row's feedback as digits: `0` absent, `1` present, `2` correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics for rows that are not `WORD_LENGTH` letters long, such as rows from a
+    /// `WordleN<6>` game; [`GuessResult::pattern_code`] works for any length.
+    pub fn pattern_digits(&self) -> [u8; WORD_LENGTH] {
+        assert_eq!(
+            self.letters.len(),
+            WORD_LENGTH,
+            "pattern_digits needs a {WORD_LENGTH}-letter row"
+        );
+        let mut digits = [PATTERN_ABSENT; WORD_LENGTH];
+        for (digit, state) in digits.iter_mut().zip(&self.letters) {
+            *digit = state.rank();
+        }
+        digits
+    }
+
+    fn pattern_string(&self) -> String {
+        self.letters
+            .iter()
+            .map(|state| match state {
+                LetterState::Correct(_) => 'G',
+                LetterState::Present(_) => 'Y',
+                LetterState::Absent(_) => 'B',
+            })
+            .collect()
+    }
+
+    /// Converts the row into share-style emoji squares (`🟩`, `🟨`, and `⬛` or `⬜`).
+    pub fn emoji_string(&self, dark_mode: bool) -> String {
+        let absent = if dark_mode { '⬛' } else { '⬜' };
+        self.letters
+            .iter()
+            .map(|state| match state {
+                LetterState::Correct(_) => '🟩',
+                LetterState::Present(_) => '🟨',
+                LetterState::Absent(_) => absent,
+            })
+            .collect()
+    }
+
+    /// Converts the scored row into a colored string ready for terminal output.
+    pub fn colored_string(&self) -> String {
+        self.colored_string_with_theme(&Theme::default())
+    }
+
+    /// Like [`GuessResult::colored_string`], painting tiles with `theme` instead.
+    pub fn colored_string_with_theme(&self, theme: &Theme) -> String {
+        self.letters
+            .iter()
+            .enumerate()
+            .map(|(position, state)| match &theme.extra_copy {
+                Some(code) if self.is_extra_copy(position) => state.colored_block(code),
+                _ => state.colored_block(theme.color_code(state)),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Whether the tile at `position` is absent even though its letter is in the word: the
+    /// guess repeats the letter, and another copy in this row is correct or present.
+    ///
+    /// ALLOT guessed against APPLE, for example, marks its second `L` absent because the
+    /// secret has only one. Scoring uses up a letter's copies before later ones are marked
+    /// absent, so the row alone tells them apart. Fibble rows are judged as displayed, lies
+    /// included.
+    pub fn is_extra_copy(&self, position: usize) -> bool {
+        match self.letters.get(position) {
+            Some(LetterState::Absent(letter)) => self
+                .letters
+                .iter()
+                .any(|state| state.letter() == *letter && !state.is_absent()),
+            _ => false,
+        }
+    }
+
+    /// Renders the row without escape codes, for pipes and terminals without color.
+    ///
+    /// Correct letters are bracketed (`[C]`), present letters parenthesized (`(A)`), and
+    /// absent letters lowercase (`n`).
+    pub fn plain_string(&self) -> String {
+        self.letters
+            .iter()
+            .map(LetterState::plain_block)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 }
 
-fn score(secret: &str, guess: &str) -> Vec<LetterState> {
-    let pattern_digits = compute_pattern_digits(secret.as_bytes(), guess.as_bytes());
-    guess
-        .as_bytes()
-        .iter()
-        .zip(pattern_digits.iter())
-        .map(|(&guess_byte, &digit)| {
-            let ch = char::from(guess_byte);
-            match digit {
-                PATTERN_CORRECT => LetterState::Correct(ch),
-                PATTERN_PRESENT => LetterState::Present(ch),
-                _ => LetterState::Absent(ch),
+impl fmt::Display for GuessResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.colored_string())
+    }
+}
+
+/// Reads a shared emoji grid back into the pattern digits of each row.
+///
+/// Letters cannot be recovered from a share, so each row becomes `WORD_LENGTH` digits (`0`
+/// absent, `1` present, `2` correct). Both `⬛` and `⬜` count as absent. Blank lines and lines
+/// without any squares, such as the `Wordle 4/6` header, are skipped.
+pub fn parse_emoji_grid(text: &str) -> Result<Vec<[u8; WORD_LENGTH]>, ParseError> {
+    let mut rows = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let line_number = idx + 1;
+        if !line.chars().any(|ch| emoji_digit(ch).is_some()) {
+            continue;
+        }
+
+        let mut digits = Vec::with_capacity(WORD_LENGTH);
+        // Some platforms append a variation selector to each square.
+        for ch in line
+            .chars()
+            .filter(|ch| !ch.is_whitespace() && *ch != '\u{fe0f}')
+        {
+            let digit = emoji_digit(ch).ok_or(ParseError::UnknownSquare {
+                line: line_number,
+                square: ch,
+            })?;
+            digits.push(digit);
+        }
+
+        let row: [u8; WORD_LENGTH] =
+            digits
+                .as_slice()
+                .try_into()
+                .map_err(|_| ParseError::WrongRowLength {
+                    line: line_number,
+                    found: digits.len(),
+                })?;
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn emoji_digit(square: char) -> Option<u8> {
+    match square {
+        '🟩' => Some(PATTERN_CORRECT),
+        '🟨' => Some(PATTERN_PRESENT),
+        '⬛' | '⬜' => Some(PATTERN_ABSENT),
+        _ => None,
+    }
+}
+
+/// Summary information about a guess evaluated against every possible secret word.
+#[derive(Debug, Clone)]
+pub struct GuessEntropy {
+    guess: String,
+    pattern_counts: Vec<usize>,
+}
+
+impl GuessEntropy {
+    /// Returns the normalized (uppercase) guess word.
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    /// Returns the number of secrets considered when computing the entropy.
+    pub fn total_secrets(&self) -> usize {
+        self.pattern_counts.iter().sum()
+    }
+
+    /// Returns each color pattern and how many secrets yield it.
+    pub fn pattern_counts(&self) -> Vec<(String, usize)> {
+        self.pattern_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(code, count)| (pattern_code_to_string(code, self.guess.len()), *count))
+            .collect()
+    }
+
+    /// Returns how many distinct patterns were observed.
+    pub fn distinct_patterns(&self) -> usize {
+        self.pattern_counts
+            .iter()
+            .filter(|count| **count > 0)
+            .count()
+    }
+
+    /// Returns the count for every pattern code, zeros included, so index `code` holds the
+    /// secrets that show [`pattern_code_to_string`]`(code)`. Has [`pattern_space`] entries.
+    pub fn full_distribution(&self) -> &[usize] {
+        &self.pattern_counts
+    }
+
+    /// Returns the pattern the most secrets show and how many show it, the bucket minimax
+    /// guessing tries to shrink. Ties go to the lowest pattern code.
+    pub fn most_likely_pattern(&self) -> (String, usize) {
+        let mut best = (0, 0);
+        for (code, &count) in self.pattern_counts.iter().enumerate() {
+            if count > best.1 {
+                best = (code, count);
             }
+        }
+        (pattern_code_to_string(best.0, self.guess.len()), best.1)
+    }
+
+    fn largest_bucket(&self) -> usize {
+        self.pattern_counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Returns the fraction of secrets that show `pattern`, written like `GYBBB`.
+    ///
+    /// Returns `None` for malformed patterns or when no secrets were considered.
+    pub fn probability_of(&self, pattern: &str) -> Option<f64> {
+        let total = self.total_secrets();
+        if total == 0 || self.guess.len() != WORD_LENGTH {
+            return None;
+        }
+        let code = encode_pattern(&parse_pattern_digits(pattern).ok()?);
+        Some(self.pattern_counts[code] as f64 / total as f64)
+    }
+
+    /// Returns how many candidates are expected to remain after this guess, `sum(count²) /
+    /// total`.
+    pub fn expected_remaining(&self) -> f64 {
+        let total = self.total_secrets();
+        if total == 0 {
+            return 0.0;
+        }
+        let squares: usize = self.pattern_counts.iter().map(|count| count * count).sum();
+        squares as f64 / total as f64
+    }
+
+    /// Returns the share of candidates this guess is expected to rule out, from `0.0` to just
+    /// under `1.0`: `1 - expected_remaining() / total_secrets()`.
+    ///
+    /// A friendlier framing than bits. It is `0.0` for an empty or single-candidate pool.
+    pub fn expected_reduction_fraction(&self) -> f64 {
+        let total = self.total_secrets();
+        if total == 0 {
+            return 0.0;
+        }
+        1.0 - self.expected_remaining() / total as f64
+    }
+
+    /// Computes the Shannon entropy (in bits) of the pattern distribution.
+    pub fn entropy_bits(&self) -> f64 {
+        entropy_bits(&self.pattern_counts)
+    }
+
+    /// Computes the entropy in nats (natural log), matching tools such as
+    /// `scipy.stats.entropy` with their default base.
+    pub fn entropy_nats(&self) -> f64 {
+        self.entropy_base(std::f64::consts::E)
+    }
+
+    /// Computes the entropy in logarithm base `base`; `2.0` gives bits. `base` must be positive
+    /// and not `1.0`, or the result is not finite.
+    pub fn entropy_base(&self, base: f64) -> f64 {
+        self.entropy_bits() / base.log2()
+    }
+
+    /// Combines analyses of the same guess against disjoint candidate pools by summing their
+    /// pattern counts, for example to reduce the shards of a parallel scan.
+    ///
+    /// Errors with [`MergeError::GuessMismatch`] when the two analyses are of different words.
+    pub fn merge(&self, other: &GuessEntropy) -> Result<GuessEntropy, MergeError> {
+        if self.guess != other.guess {
+            return Err(MergeError::GuessMismatch {
+                left: self.guess.clone(),
+                right: other.guess.clone(),
+            });
+        }
+        Ok(GuessEntropy {
+            guess: self.guess.clone(),
+            pattern_counts: self
+                .pattern_counts
+                .iter()
+                .zip(&other.pattern_counts)
+                .map(|(left, right)| left + right)
+                .collect(),
         })
-        .collect()
+    }
+}
+
+/// Serializes the summary a script would want rather than the raw histogram: `guess`,
+/// `total_secrets`, `distinct_patterns`, `entropy_bits`, and `patterns` as `[pattern, count]`
+/// pairs.
+#[cfg(feature = "serde")]
+impl Serialize for GuessEntropy {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("GuessEntropy", 5)?;
+        state.serialize_field("guess", &self.guess)?;
+        state.serialize_field("total_secrets", &self.total_secrets())?;
+        state.serialize_field("distinct_patterns", &self.distinct_patterns())?;
+        state.serialize_field("entropy_bits", &self.entropy_bits())?;
+        state.serialize_field("patterns", &self.pattern_counts())?;
+        state.end()
+    }
 }
 
-fn apply_fibble_lie(letters: &mut [LetterState]) {
-    if letters.is_empty() {
-        return;
+/// Like [`GuessEntropy`], but each secret contributes its prior weight instead of a count.
+#[derive(Debug, Clone)]
+pub struct WeightedGuessEntropy {
+    guess: String,
+    pattern_weights: Vec<f64>,
+}
+
+impl WeightedGuessEntropy {
+    /// Returns the normalized (uppercase) guess word.
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    /// Returns the summed weight of every secret considered.
+    pub fn total_weight(&self) -> f64 {
+        self.pattern_weights.iter().sum()
+    }
+
+    /// Computes the Shannon entropy (in bits) of the weighted pattern distribution.
+    pub fn entropy_bits(&self) -> f64 {
+        weighted_entropy_bits(&self.pattern_weights)
+    }
+}
+
+/// Errors that can occur while creating a game or submitting guesses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordleError {
+    InvalidLength {
+        expected: usize,
+        found: usize,
+    },
+    NonAlphabetic {
+        word: String,
+    },
+    UnknownWord {
+        word: String,
+    },
+    NotACandidate {
+        word: String,
+    },
+    EmptyWordList {
+        word_length: usize,
+    },
+    TooManyLies {
+        lies: usize,
+        max: usize,
+    },
+    TooManyGuesses {
+        max: usize,
+        found: usize,
+    },
+    HardModeViolation {
+        reason: String,
+    },
+    RepeatedGuess {
+        word: String,
+    },
+    InvalidPattern {
+        pattern: String,
+    },
+    Unsolved {
+        attempts: usize,
+    },
+    /// Every allowed guess has been used without finding the secret.
+    OutOfAttempts {
+        max: usize,
+    },
+    /// The secret has already been guessed.
+    AlreadySolved,
+    /// A multi-board game was started without any secrets.
+    NoBoards,
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordleError::InvalidLength { expected, found } => write!(
+                f,
+                "expected a {expected}-letter word, but found {found} letters"
+            ),
+            WordleError::NonAlphabetic { word } => {
+                write!(f, "{word:?} contains characters other than the letters A-Z")
+            }
+            WordleError::UnknownWord { .. } => write!(f, "that word is not in the Wordle list"),
+            WordleError::NotACandidate { word } => {
+                write!(f, "{word} has already been ruled out by earlier guesses")
+            }
+            WordleError::EmptyWordList { word_length } => {
+                write!(f, "the word list contains no {word_length}-letter words")
+            }
+            WordleError::TooManyLies { lies, max } => {
+                write!(f, "cannot lie on {lies} tiles of a {max}-letter word")
+            }
+            WordleError::TooManyGuesses { max, found } => {
+                write!(f, "expected at most {max} guesses, but found {found}")
+            }
+            WordleError::HardModeViolation { reason } => write!(f, "hard mode: {reason}"),
+            WordleError::RepeatedGuess { word } => write!(f, "{word} has already been guessed"),
+            WordleError::InvalidPattern { pattern } => {
+                write!(
+                    f,
+                    "{pattern:?} is not a pattern; use G, Y, or B for each tile"
+                )
+            }
+            WordleError::Unsolved { attempts } => {
+                write!(f, "the secret was not found within {attempts} guesses")
+            }
+            WordleError::OutOfAttempts { max } => {
+                write!(f, "all {max} guesses have been used")
+            }
+            WordleError::AlreadySolved => write!(f, "the secret has already been guessed"),
+            WordleError::NoBoards => write!(f, "a multi-board game needs at least one secret"),
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
+
+/// Errors that can occur while parsing a shared emoji grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    WrongRowLength { line: usize, found: usize },
+    UnknownSquare { line: usize, square: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::WrongRowLength { line, found } => write!(
+                f,
+                "line {line}: expected {WORD_LENGTH} squares, but found {found}"
+            ),
+            ParseError::UnknownSquare { line, square } => {
+                write!(f, "line {line}: {square:?} is not a Wordle square")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Problems with the bundled word lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataError {
+    /// These secrets are not in the allowed list, so they could never be guessed.
+    SecretsNotAllowed { words: Vec<String> },
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataError::SecretsNotAllowed { words } => write!(
+                f,
+                "{} secret word(s) missing from the allowed list: {}",
+                words.len(),
+                words.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
+
+/// Errors from [`GuessEntropy::merge`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    GuessMismatch { left: String, right: String },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeError::GuessMismatch { left, right } => {
+                write!(
+                    f,
+                    "cannot merge analyses of different guesses: {left} and {right}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Errors that can occur while reading a `G`/`Y`/`B` pattern string such as `GYBBB`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    WrongLength { expected: usize, found: usize },
+    InvalidChar { found: char },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::WrongLength { expected, found } => write!(
+                f,
+                "expected a {expected}-tile pattern, but found {found} tiles"
+            ),
+            PatternError::InvalidChar { found } => {
+                write!(f, "{found:?} is not a pattern tile; use G, Y, or B")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+fn normalize(word: &str) -> Result<String, WordleError> {
+    normalize_length(word, WORD_LENGTH)
+}
+
+/// Uppercases `word` after checking it is `expected` ASCII letters. Anything else, such as an
+/// accented letter, is rejected up front so scoring only ever sees `A`-`Z`.
+fn normalize_length(word: &str, expected: usize) -> Result<String, WordleError> {
+    if !word.chars().all(|ch| ch.is_ascii_alphabetic()) {
+        return Err(WordleError::NonAlphabetic {
+            word: word.to_string(),
+        });
+    }
+    let len = word.chars().count();
+    if len != expected {
+        return Err(WordleError::InvalidLength {
+            expected,
+            found: len,
+        });
+    }
+
+    Ok(word.to_ascii_uppercase())
+}
+
+fn ensure_allowed(word: &str) -> Result<(), WordleError> {
+    if WORDLE_ALLOWED_SET.contains(word) {
+        Ok(())
+    } else {
+        Err(WordleError::UnknownWord {
+            word: word.to_string(),
+        })
+    }
+}
+
+/// Scores `guess` against `secret` without creating a game, validating both words first.
+pub fn score_guess(secret: &str, guess: &str) -> Result<Vec<LetterState>, WordleError> {
+    let normalized_secret = normalize(secret)?;
+    ensure_allowed(&normalized_secret)?;
+    let normalized_guess = normalize(guess)?;
+    ensure_allowed(&normalized_guess)?;
+    try_score_n::<WORD_LENGTH>(&normalized_secret, &normalized_guess)
+}
+
+/// Returns the `G`/`Y`/`B` pattern `guess` would show if the answer were `secret`, such as
+/// `GYBBB`, validating both words like [`score_guess`].
+pub fn pattern_for(secret: &str, guess: &str) -> Result<String, WordleError> {
+    let secret = normalize(secret)?;
+    ensure_allowed(&secret)?;
+    let guess = normalize(guess)?;
+    ensure_allowed(&guess)?;
+    let digits = compute_pattern_digits::<WORD_LENGTH>(secret.as_bytes(), guess.as_bytes());
+    Ok(pattern_code_to_string(encode_pattern(&digits), WORD_LENGTH))
+}
+
+/// Pairs each letter of `word` with its `G`/`Y`/`B` tile in `pattern`, e.g. `("SLATE",
+/// "GYBBB")`, to build rows by hand for fixtures or reported games.
+///
+/// Both are case-insensitive and must be [`WORD_LENGTH`] long; the word need not be in the
+/// allowed list. A bad word is reported as for a guess, and any problem with the pattern,
+/// including its length, as [`WordleError::InvalidPattern`].
+pub fn letter_states(word: &str, pattern: &str) -> Result<Vec<LetterState>, WordleError> {
+    let word = normalize(word)?;
+    let digits = parse_pattern_digits(&pattern.to_ascii_uppercase()).map_err(|_| {
+        WordleError::InvalidPattern {
+            pattern: pattern.to_string(),
+        }
+    })?;
+    Ok(letters_from_digits(&word, digits))
+}
+
+fn score(secret: &str, guess: &str) -> Vec<LetterState> {
+    score_n::<WORD_LENGTH>(secret, guess)
+}
+
+fn score_n<const N: usize>(secret: &str, guess: &str) -> Vec<LetterState> {
+    letters_from_digits(
+        guess,
+        compute_pattern_digits::<N>(secret.as_bytes(), guess.as_bytes()),
+    )
+}
+
+/// Like [`score_n`], but reports words the core scorer cannot handle as errors rather than
+/// trusting that both were normalized.
+fn try_score_n<const N: usize>(secret: &str, guess: &str) -> Result<Vec<LetterState>, WordleError> {
+    let digits =
+        try_compute_pattern_digits::<N>(secret.as_bytes(), guess.as_bytes()).map_err(|err| {
+            match err {
+                ScoreError::WrongLength { expected, .. } => {
+                    let word = if secret.len() == expected {
+                        guess
+                    } else {
+                        secret
+                    };
+                    let found = word.chars().count();
+                    // The right number of characters in the wrong number of bytes means some
+                    // are not ASCII.
+                    if found == expected {
+                        WordleError::NonAlphabetic {
+                            word: word.to_string(),
+                        }
+                    } else {
+                        WordleError::InvalidLength { expected, found }
+                    }
+                }
+                ScoreError::NotALetter { .. } => WordleError::NonAlphabetic {
+                    word: if secret.bytes().all(|byte| byte.is_ascii_uppercase()) {
+                        guess.to_string()
+                    } else {
+                        secret.to_string()
+                    },
+                },
+            }
+        })?;
+    Ok(letters_from_digits(guess, digits))
+}
+
+fn letters_from_digits<const N: usize>(guess: &str, pattern_digits: [u8; N]) -> Vec<LetterState> {
+    guess
+        .as_bytes()
+        .iter()
+        .zip(pattern_digits.iter())
+        .map(|(&guess_byte, &digit)| {
+            let ch = char::from(guess_byte);
+            match digit {
+                PATTERN_CORRECT => LetterState::Correct(ch),
+                PATTERN_PRESENT => LetterState::Present(ch),
+                _ => LetterState::Absent(ch),
+            }
+        })
+        .collect()
+}
+
+/// Scores `guess` against `secret` the slow, obvious way, to cross-check the optimized scorer.
+///
+/// Both words are compared as given, without normalization, and must have the same number of
+/// characters. The first pass marks greens and strikes those letters from a copy of the
+/// secret; the second pass turns each remaining guess letter yellow by striking the first
+/// unclaimed copy. Available to other crates with the `testing` feature.
+///
+/// # Panics
+///
+/// Panics when `secret` and `guess` have different numbers of characters.
+#[cfg(any(test, feature = "testing"))]
+pub fn score_reference(secret: &str, guess: &str) -> Vec<LetterState> {
+    let guess: Vec<char> = guess.chars().collect();
+    let mut unclaimed: Vec<Option<char>> = secret.chars().map(Some).collect();
+    assert_eq!(
+        unclaimed.len(),
+        guess.len(),
+        "words must have the same length"
+    );
+
+    let mut states: Vec<Option<LetterState>> = vec![None; guess.len()];
+    for (idx, &letter) in guess.iter().enumerate() {
+        if unclaimed[idx] == Some(letter) {
+            states[idx] = Some(LetterState::Correct(letter));
+            unclaimed[idx] = None;
+        }
+    }
+    for (idx, &letter) in guess.iter().enumerate() {
+        if states[idx].is_some() {
+            continue;
+        }
+        states[idx] = Some(
+            match unclaimed.iter().position(|slot| *slot == Some(letter)) {
+                Some(slot) => {
+                    unclaimed[slot] = None;
+                    LetterState::Present(letter)
+                }
+                None => LetterState::Absent(letter),
+            },
+        );
+    }
+    states.into_iter().flatten().collect()
+}
+
+/// Explains how `guess` ignores the hints in `history`, or returns `None` if it reuses them all.
+///
+/// Greens from every row must stay in place. Each letter must appear at least as often as any
+/// single row showed it green or yellow, so two yellow `L`s demand two `L`s.
+fn hard_mode_violation(history: &[GuessResult], guess: &str) -> Option<String> {
+    let guess: Vec<char> = guess.chars().collect();
+    let mut required: HashMap<char, usize> = HashMap::new();
+
+    for row in history {
+        let mut shown: HashMap<char, usize> = HashMap::new();
+        for (idx, state) in row.letters.iter().enumerate() {
+            match state {
+                LetterState::Correct(letter) if guess.get(idx) != Some(letter) => {
+                    return Some(format!("{} letter must be {letter}", ordinal(idx + 1)));
+                }
+                LetterState::Correct(letter) | LetterState::Present(letter) => {
+                    *shown.entry(*letter).or_default() += 1;
+                }
+                LetterState::Absent(_) => {}
+            }
+        }
+        for (letter, count) in shown {
+            let needed = required.entry(letter).or_default();
+            *needed = (*needed).max(count);
+        }
+    }
+
+    let mut required: Vec<(char, usize)> = required.into_iter().collect();
+    required.sort_unstable();
+    required.into_iter().find_map(|(letter, needed)| {
+        let found = guess.iter().filter(|ch| **ch == letter).count();
+        match needed {
+            _ if found >= needed => None,
+            1 => Some(format!("guess must contain {letter}")),
+            _ => Some(format!("guess must contain {needed} {letter}s")),
+        }
+    })
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+fn apply_fibble_lies(letters: &mut [LetterState], count: usize, rng: &mut impl Rng) -> Vec<usize> {
+    let count = count.min(letters.len());
+    let mut lie_indices = rand::seq::index::sample(rng, letters.len(), count).into_vec();
+    lie_indices.sort_unstable();
+    for &lie_index in &lie_indices {
+        let original = letters[lie_index].clone();
+        letters[lie_index] = random_lie_state(&original, rng);
+    }
+    lie_indices
+}
+
+fn random_lie_state(state: &LetterState, rng: &mut impl Rng) -> LetterState {
+    let letter = state.letter();
+    match state {
+        LetterState::Correct(_) => match rng.gen_range(0..2) {
+            0 => LetterState::Present(letter),
+            _ => LetterState::Absent(letter),
+        },
+        LetterState::Present(_) => match rng.gen_range(0..2) {
+            0 => LetterState::Correct(letter),
+            _ => LetterState::Absent(letter),
+        },
+        LetterState::Absent(_) => match rng.gen_range(0..2) {
+            0 => LetterState::Correct(letter),
+            _ => LetterState::Present(letter),
+        },
+    }
+}
+
+/// Computes the entropy of a guess against every known secret word.
+pub fn analyze_guess(guess: &str) -> Result<GuessEntropy, WordleError> {
+    let guess = normalize(guess)?;
+    ensure_allowed(&guess)?;
+    Ok(analyze_word_against(
+        Word::parse(&guess)?,
+        word::secret_word_list().iter().copied(),
+    ))
+}
+
+/// Computes the entropy of a guess against an arbitrary list of secret candidates.
+///
+/// The words are parsed into [`Word`]s and scored with [`analyze_word_against`]. Secrets are
+/// case-insensitive; the first that is not a `WORD_LENGTH`-letter word is returned as an error.
+pub fn analyze_guess_against<'a>(
+    guess: &str,
+    secrets: impl IntoIterator<Item = &'a str>,
+) -> Result<GuessEntropy, WordleError> {
+    let guess = normalize(guess)?;
+    ensure_allowed(&guess)?;
+    let guess = Word::parse(&guess)?;
+
+    let mut invalid = None;
+    let secrets = secrets
+        .into_iter()
+        .map_while(|secret| Word::parse(secret).map_err(|err| invalid = Some(err)).ok());
+    let analysis = analyze_word_against(guess, secrets);
+    match invalid {
+        Some(err) => Err(err),
+        None => Ok(analysis),
+    }
+}
+
+/// Computes the entropy of an `N`-letter guess against a list of `N`-letter secret candidates.
+pub fn analyze_guess_against_n<'a, const N: usize>(
+    guess: &str,
+    secrets: impl IntoIterator<Item = &'a str>,
+) -> Result<GuessEntropy, WordleError> {
+    let normalized_guess = normalize_length(guess, N)?;
+    ensure_allowed(&normalized_guess)?;
+
+    let mut pattern_counts = vec![0usize; pattern_space(N)];
+    let guess_bytes = normalized_guess.as_bytes();
+    for secret in secrets {
+        let digits = compute_pattern_digits::<N>(secret.as_bytes(), guess_bytes);
+        let pattern_code = encode_pattern(&digits);
+        pattern_counts[pattern_code] += 1;
+    }
+
+    Ok(GuessEntropy {
+        guess: normalized_guess,
+        pattern_counts,
+    })
+}
+
+/// Computes the entropy of a guess when each secret carries a prior weight, such as a word
+/// frequency.
+///
+/// Weights must be non-negative but need not sum to one; they are normalized when the entropy
+/// is computed.
+pub fn analyze_guess_weighted<'a>(
+    guess: &str,
+    secrets: impl IntoIterator<Item = (&'a str, f64)>,
+) -> Result<WeightedGuessEntropy, WordleError> {
+    let normalized_guess = normalize(guess)?;
+    ensure_allowed(&normalized_guess)?;
+
+    let mut pattern_weights = vec![0.0; PATTERN_SPACE];
+    let guess_bytes = normalized_guess.as_bytes();
+    for (secret, weight) in secrets {
+        let digits = compute_pattern_digits::<WORD_LENGTH>(secret.as_bytes(), guess_bytes);
+        pattern_weights[encode_pattern(&digits)] += weight;
+    }
+
+    Ok(WeightedGuessEntropy {
+        guess: normalized_guess,
+        pattern_weights,
+    })
+}
+
+/// Parses a `WORD_LENGTH`-tile pattern such as `GYBBB` into the code the entropy histogram
+/// uses, the inverse of [`pattern_code_to_string`].
+///
+/// Only uppercase `G`, `Y`, and `B` are accepted.
+pub fn pattern_string_to_code(pattern: &str) -> Result<usize, PatternError> {
+    parse_pattern_digits(pattern).map(|digits| encode_pattern(&digits))
+}
+
+/// Reads an uppercase `G`/`Y`/`B` pattern into its digits.
+fn parse_pattern_digits(pattern: &str) -> Result<[u8; WORD_LENGTH], PatternError> {
+    let found = pattern.chars().count();
+    if found != WORD_LENGTH {
+        return Err(PatternError::WrongLength {
+            expected: WORD_LENGTH,
+            found,
+        });
+    }
+
+    let mut digits = [PATTERN_ABSENT; WORD_LENGTH];
+    for (digit, ch) in digits.iter_mut().zip(pattern.chars()) {
+        *digit = match ch {
+            'G' => PATTERN_CORRECT,
+            'Y' => PATTERN_PRESENT,
+            'B' => PATTERN_ABSENT,
+            found => return Err(PatternError::InvalidChar { found }),
+        };
+    }
+    Ok(digits)
+}
+
+fn secret_matches_history<const N: usize>(secret: &str, game: &WordleN<N>) -> bool {
+    game.guesses
+        .iter()
+        .all(|row| secret_matches_row(secret, row, game))
+}
+
+/// Whether `secret` could have produced `row` under `game`'s rules.
+fn secret_matches_row<const N: usize>(secret: &str, row: &GuessResult, game: &WordleN<N>) -> bool {
+    match game.mode {
+        GameMode::Wordle | GameMode::Absurdle => {
+            try_score_n::<N>(secret, row.guess()).is_ok_and(|letters| letters == row.letters)
+        }
+        GameMode::Fibble => fibble_guess_matches::<N>(secret, row, &game.lie_range()),
+    }
+}
+
+fn fibble_history_matches<const N: usize>(
+    secret: &str,
+    guesses: &[GuessResult],
+    lies: RangeInclusive<usize>,
+) -> bool {
+    guesses
+        .iter()
+        .all(|guess| fibble_guess_matches::<N>(secret, guess, &lies))
+}
+
+/// Whether `secret` explains `guess` with a number of lied tiles inside `lies`.
+fn fibble_guess_matches<const N: usize>(
+    secret: &str,
+    guess: &GuessResult,
+    lies: &RangeInclusive<usize>,
+) -> bool {
+    lie_positions::<N>(secret, guess).is_ok_and(|positions| lies.contains(&positions.len()))
+}
+
+/// Returns the positions where `guess`'s displayed feedback differs from the true score
+/// against `secret` (a `WORD_LENGTH`-letter word).
+///
+/// For a secret consistent with a standard Fibble row this is exactly one index: the lied
+/// tile. Any other length means the secret cannot have produced the row, and the full set of
+/// mismatches is returned so callers can see how far off it is. Errors if `secret` is not a
+/// `WORD_LENGTH`-letter word or the row's guess is malformed.
+pub fn fibble_lie_candidates(secret: &str, guess: &GuessResult) -> Result<Vec<usize>, WordleError> {
+    lie_positions::<WORD_LENGTH>(&normalize(secret)?, guess)
+}
+
+/// Why a word is no longer a possible secret, as found by [`explain_elimination`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Elimination {
+    /// Row `guess_index` showed `shown` at `position`, but the word would have scored that
+    /// tile as `actual`. Reported for the first such tile.
+    Tile {
+        guess_index: usize,
+        position: usize,
+        shown: LetterState,
+        actual: LetterState,
+    },
+    /// In Fibble, row `guess_index` differs from the word's true score on `mismatches`
+    /// tiles, which is not a number of lies the game tells.
+    LieCount {
+        guess_index: usize,
+        mismatches: usize,
+    },
+}
+
+impl Elimination {
+    /// Returns the index into [`WordleN::guesses`] of the row that rules the word out.
+    pub fn guess_index(&self) -> usize {
+        match self {
+            Elimination::Tile { guess_index, .. } | Elimination::LieCount { guess_index, .. } => {
+                *guess_index
+            }
+        }
+    }
+}
+
+/// Finds the first row of `game` that `word` could not have produced, or `None` if `word` is
+/// still consistent with every row.
+///
+/// Wordle and Absurdle rows are compared tile by tile against the word's true score; Fibble
+/// rows are checked for a permitted number of lies, as [`remaining_secrets`] does. The word is
+/// case-insensitive and need not be an allowed guess.
+pub fn explain_elimination<const N: usize>(
+    game: &WordleN<N>,
+    word: &str,
+) -> Result<Option<Elimination>, WordleError> {
+    let word = normalize_length(word, N)?;
+    let lies = game.lie_range();
+    for (guess_index, row) in game.guesses.iter().enumerate() {
+        let elimination = match game.mode {
+            GameMode::Wordle | GameMode::Absurdle => try_score_n::<N>(&word, row.guess())?
+                .into_iter()
+                .zip(&row.letters)
+                .enumerate()
+                .find(|(_, (actual, shown))| actual != *shown)
+                .map(|(position, (actual, shown))| Elimination::Tile {
+                    guess_index,
+                    position,
+                    shown: shown.clone(),
+                    actual,
+                }),
+            GameMode::Fibble => {
+                let mismatches = lie_positions::<N>(&word, row)?.len();
+                (!lies.contains(&mismatches)).then_some(Elimination::LieCount {
+                    guess_index,
+                    mismatches,
+                })
+            }
+        };
+        if elimination.is_some() {
+            return Ok(elimination);
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the words from `pool` that could have shown every row of a standard Fibble game,
+/// where exactly one tile per row lies.
+///
+/// Pool words are case-insensitive and returned as given; words that are not
+/// [`WORD_LENGTH`] ASCII letters are skipped.
+pub fn fibble_remaining_from<'a>(
+    pool: impl IntoIterator<Item = &'a str>,
+    rows: &[GuessResult],
+) -> Vec<&'a str> {
+    let lies = DEFAULT_FIBBLE_LIES..=DEFAULT_FIBBLE_LIES;
+    pool.into_iter()
+        .filter(|secret| {
+            normalize(secret)
+                .is_ok_and(|word| fibble_history_matches::<WORD_LENGTH>(&word, rows, lies.clone()))
+        })
+        .collect()
+}
+
+fn lie_positions<const N: usize>(
+    secret: &str,
+    guess: &GuessResult,
+) -> Result<Vec<usize>, WordleError> {
+    Ok(try_score_n::<N>(secret, guess.guess())?
+        .iter()
+        .zip(guess.letters())
+        .enumerate()
+        .filter(|(_, (actual, reported))| actual != reported)
+        .map(|(idx, _)| idx)
+        .collect())
+}
+
+/// Returns the list of remaining possible secret words for the provided game state.
+///
+/// Games built with [`WordleN::tracking_candidates`] answer from their tracked set; others
+/// check every secret against the whole history.
+pub fn remaining_secrets<const N: usize>(game: &WordleN<N>) -> Vec<&'static str> {
+    remaining_secrets_iter(game).collect()
+}
+
+/// Like [`remaining_secrets`], but filters lazily, for callers that only need the first match
+/// or want to stop early.
+pub fn remaining_secrets_iter<const N: usize>(
+    game: &WordleN<N>,
+) -> impl Iterator<Item = &'static str> + '_ {
+    let (tracked, scanned) = if game.track_candidates {
+        (Some(game.tracked.iter().copied()), None)
+    } else {
+        let scanned = secret_words_of_length(N)
+            .iter()
+            .map(|word| word.as_str())
+            .filter(move |secret| secret_matches_history(secret, game));
+        (None, Some(scanned))
+    };
+    tracked
+        .into_iter()
+        .flatten()
+        .chain(scanned.into_iter().flatten())
+}
+
+/// Returns how many secrets are still consistent with `game`, without collecting them.
+pub fn remaining_secrets_count<const N: usize>(game: &WordleN<N>) -> usize {
+    remaining_secrets_iter(game).count()
+}
+
+/// Returns the words from a caller-supplied pool that are consistent with the game's history.
+///
+/// Pool words are case-insensitive and returned as given; words that are not `N` ASCII letters
+/// are skipped.
+pub fn remaining_secrets_from<'a, const N: usize>(
+    game: &WordleN<N>,
+    pool: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    pool.into_iter()
+        .filter(|secret| {
+            normalize_length(secret, N).is_ok_and(|word| secret_matches_history(&word, game))
+        })
+        .collect()
+}
+
+/// Returns how many bits `guess` is expected to reveal about the secrets still consistent with
+/// `game`, so a chosen guess can be compared with [`best_information_guess`].
+///
+/// Returns `0.0` once at most one candidate remains; the guess is validated either way.
+pub fn information_gain(game: &Wordle, guess: &str) -> Result<f64, WordleError> {
+    let candidates = remaining_secrets(game);
+    let entropy = analyze_guess_against(guess, candidates.iter().copied())?;
+    Ok(entropy.entropy_bits())
+}
+
+/// Roughly estimates how many more guesses `game` needs, counting the one that solves it.
+///
+/// This is a heuristic, not a simulation: see [`expected_guesses_from`]. It reports `1.0` once
+/// at most one candidate remains, and otherwise ranks guesses with [`best_information_guess`].
+pub fn expected_guesses_remaining(game: &Wordle) -> f64 {
+    let candidates = remaining_secrets_count(game);
+    if candidates <= 1 {
+        return 1.0;
+    }
+    let best_bits = best_information_guess(game).map_or(0.0, |best| best.entropy_bits());
+    expected_guesses_from(candidates, best_bits)
+}
+
+/// Estimates the guesses needed to solve from `candidates` possible secrets when the best
+/// guess reveals `best_bits` of information.
+///
+/// The secret carries `log2(candidates)` bits, so at `best_bits` per guess it takes about
+/// `log2(candidates) / best_bits` guesses to pin down, plus one to play it. The estimate never
+/// drops below `1.0`, and never exceeds `candidates`, since guessing each one in turn always
+/// works.
+pub fn expected_guesses_from(candidates: usize, best_bits: f64) -> f64 {
+    if candidates <= 1 {
+        return 1.0;
+    }
+    let count = candidates as f64;
+    if best_bits <= 0.0 {
+        return count;
+    }
+    (1.0 + count.log2() / best_bits).clamp(1.0, count)
+}
+
+/// Two guesses scored against the same candidates, from [`compare_guesses`].
+#[derive(Debug, Clone)]
+pub struct GuessComparison {
+    first: GuessEntropy,
+    second: GuessEntropy,
+}
+
+impl GuessComparison {
+    /// Returns the analysis of the first guess passed to [`compare_guesses`].
+    pub fn first(&self) -> &GuessEntropy {
+        &self.first
+    }
+
+    /// Returns the analysis of the second guess passed to [`compare_guesses`].
+    pub fn second(&self) -> &GuessEntropy {
+        &self.second
+    }
+
+    /// Returns the guess with more expected information, or `None` when their entropies tie.
+    pub fn winner(&self) -> Option<&GuessEntropy> {
+        let difference = self.first.entropy_bits() - self.second.entropy_bits();
+        if difference.abs() < 1e-9 {
+            None
+        } else if difference > 0.0 {
+            Some(&self.first)
+        } else {
+            Some(&self.second)
+        }
+    }
+}
+
+/// Scores `a` and `b` against the secrets still consistent with `game`, so their entropies
+/// and [`GuessEntropy::expected_remaining`] values can be shown side by side.
+///
+/// Both words are validated before either is scored.
+pub fn compare_guesses(game: &Wordle, a: &str, b: &str) -> Result<GuessComparison, WordleError> {
+    let a = normalize(a)?;
+    let b = normalize(b)?;
+    ensure_allowed(&a)?;
+    ensure_allowed(&b)?;
+    let candidates = remaining_secrets(game);
+    Ok(GuessComparison {
+        first: analyze_guess_against(&a, candidates.iter().copied())?,
+        second: analyze_guess_against(&b, candidates.iter().copied())?,
+    })
+}
+
+/// Tunes which guesses the suggestion functions may return.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SuggestOptions {
+    /// Skip words already played in the game. In plain Wordle a replayed word carries no new
+    /// information anyway, but excluding it keeps late-game suggestions useful.
+    pub exclude_played: bool,
+    /// Which words may be suggested. Candidates are always scored against the secret list.
+    pub suggest_from: SuggestPool,
+}
+
+/// The words a suggestion may be drawn from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SuggestPool {
+    /// Any allowed guess, including obscure words that are never answers.
+    #[default]
+    AllAllowed,
+    /// Only words from the secret list, so every suggestion could be the answer. This scans
+    /// about a quarter as many words.
+    SecretsOnly,
+}
+
+impl SuggestPool {
+    fn words(self) -> &'static [String] {
+        match self {
+            SuggestPool::AllAllowed => allowed_words(),
+            SuggestPool::SecretsOnly => secret_words(),
+        }
+    }
+}
+
+impl SuggestOptions {
+    fn excluded<'a>(&self, game: &'a Wordle) -> Vec<&'a str> {
+        if self.exclude_played {
+            game.guesses().iter().map(GuessResult::guess).collect()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Returns the guess from the allowed list that maximizes the expected information gain.
+///
+/// Guesses whose entropies tie (to within a billionth of a bit) are split in favor of one that
+/// is still a possible secret, since it might win outright, and then alphabetically, so the
+/// suggestion never depends on scan order.
+///
+/// Equivalent to [`best_information_guess_thresholded`] with a threshold of 0 bits, so a
+/// suggestion is always returned while candidates remain.
+pub fn best_information_guess(game: &Wordle) -> Option<GuessEntropy> {
+    best_information_guess_thresholded(game, 0.0)
+}
+
+/// Like [`best_information_guess`], but returns `None` when even the best guess carries fewer
+/// than `min_bits` of information, signalling that the caller should just pick a candidate.
+pub fn best_information_guess_thresholded(game: &Wordle, min_bits: f64) -> Option<GuessEntropy> {
+    information_guess_among(&remaining_secrets(game)).filter(|best| best.entropy_bits() >= min_bits)
+}
+
+/// Like [`best_information_guess`], restricted by `options`.
+pub fn best_information_guess_with(
+    game: &Wordle,
+    options: &SuggestOptions,
+) -> Option<GuessEntropy> {
+    information_guess_excluding(
+        &remaining_secrets(game),
+        options.suggest_from.words(),
+        &options.excluded(game),
+    )
+}
+
+/// Returns the guess whose worst-case outcome leaves the fewest candidates.
+///
+/// Ties on the largest remaining bucket are broken in favor of higher entropy. The returned
+/// distribution can be inspected to see the size of that worst bucket.
+pub fn best_minimax_guess(game: &Wordle) -> Option<GuessEntropy> {
+    minimax_guess_among(&remaining_secrets(game))
+}
+
+/// Returns the `n` most informative guesses, sorted by descending entropy.
+///
+/// Ties are ordered as in [`best_information_guess`]: possible secrets first, then
+/// alphabetically, so the first entry is always the best guess and repeated calls are stable.
+pub fn top_information_guesses(game: &Wordle, n: usize) -> Vec<GuessEntropy> {
+    ranked_information_guesses(&remaining_secrets(game), n)
+}
+
+/// Like [`top_information_guesses`], restricted by `options`.
+pub fn top_information_guesses_with(
+    game: &Wordle,
+    n: usize,
+    options: &SuggestOptions,
+) -> Vec<GuessEntropy> {
+    ranked_information_guesses_excluding(
+        &remaining_secrets(game),
+        n,
+        options.suggest_from.words(),
+        &options.excluded(game),
+    )
+}
+
+/// Picks a guess by positional letter frequency instead of entropy.
+///
+/// Each allowed word scores the sum, over its positions, of how many candidates have the same
+/// letter in that position. A repeated letter only counts the first time, so words with
+/// distinct letters are favored. This is a single pass over the candidates and the allowed
+/// list, so it suits constrained hardware or a cold cache; ties go to the earlier allowed word.
+/// Returns `None` when `candidates` is empty.
+pub fn frequency_heuristic_guess(candidates: &[&str]) -> Option<String> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let counts = positional_letter_counts(candidates);
+    let mut best: Option<(&String, usize)> = None;
+    for guess in allowed_words() {
+        let score = positional_frequency_score(guess, &counts);
+        if best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((guess, score));
+        }
+    }
+    best.map(|(guess, _)| guess.clone())
+}
+
+/// Returns, for each position, the probability that it holds each letter (`A` at index `0`)
+/// if the answer is drawn uniformly from `candidates`.
+///
+/// Each position's probabilities sum to `1`, or are all `0` when no candidate counts. Words
+/// of the wrong length are skipped and case is ignored, as in [`frequency_heuristic_guess`].
+pub fn positional_frequencies(candidates: &[&str]) -> [[f64; 26]; WORD_LENGTH] {
+    let counts = positional_letter_counts(candidates);
+    let mut frequencies = [[0.0; 26]; WORD_LENGTH];
+    for (row, counts) in frequencies.iter_mut().zip(&counts) {
+        let total = counts.iter().sum::<usize>();
+        if total == 0 {
+            continue;
+        }
+        for (frequency, count) in row.iter_mut().zip(counts) {
+            *frequency = *count as f64 / total as f64;
+        }
+    }
+    frequencies
+}
+
+fn positional_letter_counts(candidates: &[&str]) -> [[usize; 26]; WORD_LENGTH] {
+    let mut counts = [[0usize; 26]; WORD_LENGTH];
+    for candidate in candidates.iter().filter(|word| word.len() == WORD_LENGTH) {
+        for (position, byte) in candidate.bytes().enumerate() {
+            if byte.is_ascii_alphabetic() {
+                counts[position][(byte.to_ascii_uppercase() - b'A') as usize] += 1;
+            }
+        }
+    }
+    counts
+}
+
+fn positional_frequency_score(word: &str, counts: &[[usize; 26]; WORD_LENGTH]) -> usize {
+    let mut seen = [false; 26];
+    let mut score = 0;
+    for (position, byte) in word.bytes().enumerate().take(WORD_LENGTH) {
+        let letter = (byte.to_ascii_uppercase() - b'A') as usize;
+        if !std::mem::replace(&mut seen[letter], true) {
+            score += counts[position][letter];
+        }
+    }
+    score
+}
+
+fn ranked_information_guesses(candidates: &[&str], n: usize) -> Vec<GuessEntropy> {
+    ranked_information_guesses_excluding(candidates, n, allowed_words(), &[])
+}
+
+fn ranked_information_guesses_excluding(
+    candidates: &[&str],
+    n: usize,
+    pool: &[String],
+    excluded: &[&str],
+) -> Vec<GuessEntropy> {
+    if candidates.is_empty() || n == 0 {
+        return Vec::new();
+    }
+
+    let live: HashSet<&str> = candidates.iter().copied().collect();
+    let mut ranked: Vec<GuessEntropy> = pool
+        .iter()
+        .filter(|guess| !excluded.contains(&guess.as_str()))
+        .filter_map(|guess| analyze_guess_against(guess, candidates.iter().copied()).ok())
+        .collect();
+    ranked.sort_by(|a, b| compare_suggestions(b, a, &live));
+    ranked.truncate(n);
+    ranked
+}
+
+/// Orders two scored guesses, each a word and its bits of information, by how good a
+/// suggestion each makes, `Greater` meaning `a` is better: more bits, then being one of the
+/// `live` candidates, then the alphabetically earlier word.
+///
+/// Bits are compared in billionths, so guesses that split the candidates the same way tie even
+/// if summing in a different order left them an ulp apart. This is the order
+/// [`best_information_guess`] uses, for callers ranking scores of their own.
+pub fn compare_guess_scores(
+    a: (&str, f64),
+    b: (&str, f64),
+    live: &HashSet<&str>,
+) -> std::cmp::Ordering {
+    let bits_key = |bits: f64| (bits * 1e9).round() as i64;
+    bits_key(a.1)
+        .cmp(&bits_key(b.1))
+        .then_with(|| live.contains(a.0).cmp(&live.contains(b.0)))
+        .then_with(|| b.0.cmp(a.0))
+}
+
+fn compare_suggestions(
+    a: &GuessEntropy,
+    b: &GuessEntropy,
+    live: &HashSet<&str>,
+) -> std::cmp::Ordering {
+    compare_guess_scores(
+        (a.guess(), a.entropy_bits()),
+        (b.guess(), b.entropy_bits()),
+        live,
+    )
+}
+
+fn information_guess_among(candidates: &[&str]) -> Option<GuessEntropy> {
+    information_guess_excluding(candidates, allowed_words(), &[])
+}
+
+#[cfg(not(feature = "rayon"))]
+fn information_guess_excluding(
+    candidates: &[&str],
+    pool: &[String],
+    excluded: &[&str],
+) -> Option<GuessEntropy> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let live: HashSet<&str> = candidates.iter().copied().collect();
+    pool.iter()
+        .filter(|guess| !excluded.contains(&guess.as_str()))
+        .filter_map(|guess| analyze_guess_against(guess, candidates.iter().copied()).ok())
+        .max_by(|a, b| compare_suggestions(a, b, &live))
+}
+
+/// Scans `pool` on the rayon pool. [`compare_suggestions`] is a total order on distinct
+/// words, so this suggests the same guess as the serial scan.
+#[cfg(feature = "rayon")]
+fn information_guess_excluding(
+    candidates: &[&str],
+    pool: &[String],
+    excluded: &[&str],
+) -> Option<GuessEntropy> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let live: HashSet<&str> = candidates.iter().copied().collect();
+    pool.par_iter()
+        .filter(|guess| !excluded.contains(&guess.as_str()))
+        .filter_map(|guess| analyze_guess_against(guess, candidates.iter().copied()).ok())
+        .max_by(|a, b| compare_suggestions(a, b, &live))
+}
+
+/// Suggests a guess for a standard Fibble game, where each row lies about exactly one tile.
+///
+/// A secret can show any pattern one tile away from its true one, so the feedback only rules
+/// candidates out softly. Each allowed guess is rated by its worst case: the most
+/// `candidates` that could all show the same row. Returns the guess with the smallest worst
+/// case, and that count; ties go to a possible secret, then alphabetically. Candidates are
+/// case-insensitive and malformed ones are skipped; returns `None` when none are left.
+pub fn best_fibble_guess(candidates: &[&str]) -> Option<(String, usize)> {
+    let candidates: Vec<String> = candidates
+        .iter()
+        .filter_map(|word| normalize(word).ok())
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let live: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    let mut buckets = vec![0usize; PATTERN_SPACE];
+    let mut best: Option<(&String, usize)> = None;
+    for guess in allowed_words() {
+        buckets.fill(0);
+        let mut worst = 0;
+        for secret in &candidates {
+            let digits = compute_pattern_digits::<WORD_LENGTH>(secret.as_bytes(), guess.as_bytes());
+            let code = encode_pattern(&digits);
+            let mut weight = PATTERN_SPACE / 3;
+            for digit in digits {
+                let base = code - usize::from(digit) * weight;
+                for shown in (0..3).filter(|shown| *shown != digit) {
+                    let bucket = &mut buckets[base + usize::from(shown) * weight];
+                    *bucket += 1;
+                    worst = worst.max(*bucket);
+                }
+                weight /= 3;
+            }
+        }
+
+        let better = best.is_none_or(|(best_guess, best_worst)| {
+            (worst, !live.contains(guess.as_str()), guess)
+                < (best_worst, !live.contains(best_guess.as_str()), best_guess)
+        });
+        if better {
+            best = Some((guess, worst));
+        }
+    }
+    best.map(|(guess, worst)| (guess.clone(), worst))
+}
+
+fn minimax_guess_among(candidates: &[&str]) -> Option<GuessEntropy> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    allowed_words()
+        .iter()
+        .filter_map(|guess| analyze_guess_against(guess, candidates.iter().copied()).ok())
+        .min_by(|a, b| {
+            a.largest_bucket().cmp(&b.largest_bucket()).then_with(|| {
+                b.entropy_bits()
+                    .partial_cmp(&a.entropy_bits())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+        })
+}
+
+/// Returns the uppercase list of allowed Wordle guesses.
+pub fn allowed_words() -> &'static [String] {
+    allowed_words_of_length(WORD_LENGTH)
+}
+
+/// Returns the uppercase list of canonical Wordle solutions.
+pub fn secret_words() -> &'static [String] {
+    secret_words_of_length(WORD_LENGTH)
+}
+
+/// Returns up to `limit` allowed guesses within `max_distance` edits of `word`, closest first.
+///
+/// Distance is Levenshtein over ASCII letters, compared case-insensitively; ties keep the
+/// allowed list's order. Useful for "did you mean" hints after [`WordleError::UnknownWord`].
+pub fn nearest_allowed(word: &str, max_distance: usize, limit: usize) -> Vec<&'static str> {
+    let word = word.to_ascii_uppercase();
+    let mut matches: Vec<(usize, &'static str)> = allowed_words()
+        .iter()
+        .filter_map(|candidate| {
+            bounded_edit_distance(word.as_bytes(), candidate.as_bytes(), max_distance)
+                .map(|distance| (distance, candidate.as_str()))
+        })
+        .collect();
+    matches.sort_by_key(|&(distance, _)| distance);
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Levenshtein distance between `a` and `b`, or `None` as soon as it must exceed `max`.
+fn bounded_edit_distance(a: &[u8], b: &[u8], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, &left) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &right) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left != right);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        // Every later row is at least this row's minimum.
+        if current.iter().min().is_some_and(|&best| best > max) {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[b.len()];
+    (distance <= max).then_some(distance)
+}
+
+/// Returns a hash of the bundled allowed and secret lists' contents, computed once.
+///
+/// Caches derived from the lists can store it to notice a dictionary edit that keeps every
+/// list the same length.
+pub fn word_lists_hash() -> u64 {
+    *WORD_LISTS_HASH
+}
+
+/// Hashes the words of both lists, in order, with 64-bit FNV-1a.
+pub fn word_lists_fingerprint(allowed: &[String], secrets: &[String]) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    // Separators keep word and list boundaries significant.
+    for list in [allowed, secrets] {
+        for word in list {
+            for byte in word.bytes().chain([b'\n']) {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Returns the daily puzzle number for the UTC date of `now`, counting from
+/// [`DAILY_EPOCH_DAYS`]. Dates before the epoch map to puzzle `0`.
+pub fn daily_puzzle_number(now: SystemTime) -> u64 {
+    let days = now
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or(0);
+    days.saturating_sub(DAILY_EPOCH_DAYS)
+}
+
+/// Returns the secret for daily puzzle `puzzle_number`, cycling through [`secret_words`] in
+/// list order so every player sees the same word on the same day.
+pub fn daily_secret(puzzle_number: u64) -> &'static str {
+    let words = secret_words();
+    &words[(puzzle_number % words.len() as u64) as usize]
+}
+
+/// Returns the bundled allowed guesses with the given number of letters.
+pub fn allowed_words_of_length(word_length: usize) -> &'static [String] {
+    WORDLE_ALLOWED_LISTS
+        .get(&word_length)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+/// Returns the bundled secret words with the given number of letters.
+///
+/// Secrets missing from the allowed list are left out; see [`try_secret_words`].
+pub fn secret_words_of_length(word_length: usize) -> &'static [String] {
+    WORDLE_SECRET_LISTS.of_length(word_length)
+}
+
+/// Like [`secret_words`], but errors if the bundled data files disagree: every secret must
+/// also be an allowed guess.
+///
+/// [`secret_words`] quietly drops such secrets, which keeps games playable after a bad edit
+/// to the data files; this is for tooling that should notice one.
+pub fn try_secret_words() -> Result<&'static [String], DataError> {
+    WORDLE_SECRET_LISTS.check()?;
+    Ok(secret_words())
+}
+
+/// How one tile of a prospective guess relates to the feedback seen so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlap {
+    /// The letter is already known to be correct in this position.
+    SatisfiesGreen,
+    /// The letter was reported present somewhere in an earlier guess.
+    ReusesYellow,
+    /// The letter was reported absent and never seen in the word.
+    WastesKnownAbsent,
+    /// Nothing useful is known about this letter yet.
+    Neutral,
+}
+
+/// Classifies each tile of `guess` against the greens, yellows, and absents seen so far.
+///
+/// In Fibble mode the classification is built from the displayed feedback, which may contain
+/// lies.
+pub fn guess_constraint_overlap(game: &Wordle, guess: &str) -> Result<Vec<Overlap>, WordleError> {
+    let normalized_guess = normalize(guess)?;
+    ensure_allowed(&normalized_guess)?;
+
+    let mut greens = [None; WORD_LENGTH];
+    let mut yellows = HashSet::new();
+    let mut seen_in_word = HashSet::new();
+    let mut absents = HashSet::new();
+    for row in game.guesses() {
+        for (idx, state) in row.letters().iter().enumerate() {
+            match state {
+                LetterState::Correct(ch) => {
+                    greens[idx] = Some(*ch);
+                    seen_in_word.insert(*ch);
+                }
+                LetterState::Present(ch) => {
+                    yellows.insert(*ch);
+                    seen_in_word.insert(*ch);
+                }
+                LetterState::Absent(ch) => {
+                    absents.insert(*ch);
+                }
+            }
+        }
+    }
+
+    Ok(normalized_guess
+        .chars()
+        .enumerate()
+        .map(|(idx, ch)| {
+            if greens[idx] == Some(ch) {
+                Overlap::SatisfiesGreen
+            } else if yellows.contains(&ch) {
+                Overlap::ReusesYellow
+            } else if absents.contains(&ch) && !seen_in_word.contains(&ch) {
+                Overlap::WastesKnownAbsent
+            } else {
+                Overlap::Neutral
+            }
+        })
+        .collect())
+}
+
+/// Returns the guess that best tests a hunch that `suspect` is the answer.
+///
+/// The chosen guess shares its pattern against `suspect` with as few other candidates as
+/// possible, so seeing that pattern confirms the hunch. Ties go to the guess that carries the
+/// most information about the remaining candidates, so a wrong hunch still narrows the field.
+/// Errors if `suspect` has already been ruled out by the game's history.
+pub fn confirmation_guess(game: &Wordle, suspect: &str) -> Result<&'static str, WordleError> {
+    let normalized_suspect = normalize(suspect)?;
+    ensure_allowed(&normalized_suspect)?;
+    let candidates = remaining_secrets(game);
+    if !candidates.contains(&normalized_suspect.as_str()) {
+        return Err(WordleError::NotACandidate {
+            word: normalized_suspect,
+        });
+    }
+
+    let suspect_bytes = normalized_suspect.as_bytes();
+    let best = allowed_words()
+        .iter()
+        .filter_map(|guess| {
+            let entropy = analyze_guess_against(guess, candidates.iter().copied()).ok()?;
+            let suspect_code = encode_pattern(&compute_pattern_digits::<WORD_LENGTH>(
+                suspect_bytes,
+                guess.as_bytes(),
+            ));
+            let lookalikes = entropy.pattern_counts[suspect_code] - 1;
+            Some((guess.as_str(), lookalikes, entropy.entropy_bits()))
+        })
+        .min_by(|a, b| {
+            a.1.cmp(&b.1)
+                .then_with(|| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal))
+        })
+        .map(|(guess, _, _)| guess)
+        .expect("the suspect itself is an allowed guess");
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_words_not_in_wordle_list() {
+        assert_eq!(
+            Wordle::new("zzzzz").unwrap_err(),
+            WordleError::UnknownWord {
+                word: "ZZZZZ".into()
+            }
+        );
+
+        let mut game = Wordle::new("cigar").unwrap();
+        assert_eq!(
+            game.submit_guess("zzzzz").unwrap_err(),
+            WordleError::UnknownWord {
+                word: "ZZZZZ".into()
+            }
+        );
+    }
+
+    fn truthful_row(secret: &str, guess: &str) -> GuessResult {
+        GuessResult {
+            guess: guess.into(),
+            letters: score(secret, guess),
+            lie_indices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn optional_lies_keep_a_truthful_row_consistent() {
+        let mut game = Wordle::new_fibble_with_optional_lies("cigar", 1).unwrap();
+        assert!(game.lies_are_optional());
+        game.guesses.push(truthful_row("CIGAR", "CAIRN"));
+        assert!(remaining_secrets(&game).contains(&"CIGAR"));
+    }
+
+    #[test]
+    fn exact_lies_rule_out_a_truthful_row() {
+        let mut game = Wordle::new_fibble_with_lies("cigar", 1).unwrap();
+        assert!(!game.lies_are_optional());
+        game.guesses.push(truthful_row("CIGAR", "CAIRN"));
+        assert!(!remaining_secrets(&game).contains(&"CIGAR"));
+    }
+
+    #[test]
+    fn optional_lies_still_accept_one_lie_but_not_two() {
+        let mut letters = score("CIGAR", "CAIRN");
+        letters[4] = LetterState::Present('N');
+        let one_lie = GuessResult {
+            guess: "CAIRN".into(),
+            letters,
+            lie_indices: vec![4],
+        };
+        let mut game = Wordle::new_fibble_with_optional_lies("cigar", 1).unwrap();
+        game.guesses.push(one_lie);
+        let survivors = remaining_secrets(&game);
+        assert!(survivors.contains(&"CIGAR"));
+        // CAIRN itself would need four lies to show this row.
+        assert!(!survivors.contains(&"CAIRN"));
+    }
+
+    #[test]
+    fn optional_lie_rows_never_exceed_the_bound() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut game = Wordle::new_fibble_with_optional_lies("cigar", 1).unwrap();
+        for guess in [
+            "cairn", "slate", "pound", "fight", "crane", "brick", "mound",
+        ] {
+            let row = game.submit_guess_with_rng(guess, &mut rng).unwrap();
+            assert!(row.lie_indices().len() <= 1);
+        }
+        assert!(remaining_secrets(&game).contains(&"CIGAR"));
+    }
+
+    #[test]
+    fn fibble_lie_candidates_finds_the_lied_tile() {
+        let mut letters = score("CIGAR", "CAIRN");
+        letters[4] = LetterState::Present('N');
+        let row = GuessResult {
+            guess: "CAIRN".into(),
+            letters,
+            lie_indices: vec![4],
+        };
+
+        assert_eq!(fibble_lie_candidates("cigar", &row), Ok(vec![4]));
+        // A secret that scores CAIRN as all green disagrees everywhere but the first tile.
+        assert_eq!(fibble_lie_candidates("CAIRN", &row), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(
+            fibble_lie_candidates("cig", &row),
+            Err(WordleError::InvalidLength {
+                expected: WORD_LENGTH,
+                found: 3
+            })
+        );
+        assert!(matches!(
+            fibble_lie_candidates("ci-ar", &row),
+            Err(WordleError::NonAlphabetic { .. })
+        ));
+    }
+
+    #[test]
+    fn pattern_code_matches_the_encoded_digits() {
+        let mut game = Wordle::new("cigar").unwrap();
+        let solved = game.submit_guess("cigar").unwrap().clone();
+        assert_eq!(solved.pattern_code(), PATTERN_SPACE - 1);
+        assert_eq!(solved.pattern_digits(), [PATTERN_CORRECT; WORD_LENGTH]);
+
+        let mut game = Wordle::new("cigar").unwrap();
+        let row = game.submit_guess("cairn").unwrap();
+        let expected = compute_pattern_digits::<WORD_LENGTH>(b"CIGAR", b"CAIRN");
+        assert_eq!(row.pattern_digits(), expected);
+        assert_eq!(row.pattern_code(), encode_pattern(&expected));
+        assert_eq!(
+            pattern_code_to_string(row.pattern_code(), WORD_LENGTH),
+            "GYYYB"
+        );
+    }
+
+    #[test]
+    fn letter_state_predicates_and_display() {
+        let correct = LetterState::Correct('C');
+        let present = LetterState::Present('A');
+        let absent = LetterState::Absent('T');
+
+        assert!(correct.is_correct() && !correct.is_present() && !correct.is_absent());
+        assert!(present.is_present() && !present.is_correct() && !present.is_absent());
+        assert!(absent.is_absent() && !absent.is_correct() && !absent.is_present());
+        assert_eq!(correct.to_string(), "C");
+        assert_eq!(format!("{present}{absent}"), "AT");
+    }
+
+    #[test]
+    fn inconsistent_secret_lists_skip_unknown_words_without_panicking() {
+        let allowed: HashSet<String> = ["CIGAR", "REBUT"].map(String::from).into();
+        let lists = load_secret_lists("cigar\nzzzzz\n\nrebut\nab-cd\n", &allowed);
+
+        assert_eq!(lists.of_length(WORD_LENGTH), ["CIGAR", "REBUT"]);
+        assert_eq!(
+            lists.check(),
+            Err(DataError::SecretsNotAllowed {
+                words: vec!["ZZZZZ".into()]
+            })
+        );
+    }
+
+    #[test]
+    fn bundled_secrets_are_all_allowed() {
+        assert_eq!(
+            try_secret_words().map(<[String]>::len),
+            Ok(secret_words().len())
+        );
+    }
+
+    #[test]
+    fn nearest_allowed_suggests_a_one_typo_correction() {
+        let suggestions = nearest_allowed("cigqr", 1, 5);
+        assert_eq!(suggestions, vec!["CIGAR"]);
+
+        assert_eq!(nearest_allowed("crane", 0, 5), vec!["CRANE"]);
+        assert!(nearest_allowed("qqqqqqqqq", 2, 5).is_empty());
+        assert!(nearest_allowed("crane", 1, 3).len() <= 3);
+    }
+
+    #[test]
+    fn rejects_non_alphabetic_words() {
+        assert_eq!(
+            Wordle::new("café"),
+            Err(WordleError::NonAlphabetic {
+                word: "café".into()
+            })
+        );
+
+        let mut game = Wordle::new("cigar").unwrap();
+        for guess in ["cafés", "ci9ar", "ci ar"] {
+            assert_eq!(
+                game.submit_guess(guess).unwrap_err(),
+                WordleError::NonAlphabetic { word: guess.into() }
+            );
+        }
+        assert!(game.guesses().is_empty());
+        assert!(matches!(
+            analyze_guess_against("naïve", ["CIGAR"]),
+            Err(WordleError::NonAlphabetic { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_words_of_incorrect_length() {
+        assert!(Wordle::new("tool").is_err());
+        let mut game = Wordle::new("cigar").unwrap();
+        assert!(game.submit_guess("longer").is_err());
+    }
+
+    #[test]
+    fn scores_guesses_with_duplicate_letters() {
+        let letters = score("APPLE", "ALLOT");
+        use LetterState::*;
+        assert_eq!(
+            letters,
+            vec![
+                Correct('A'),
+                Present('L'),
+                Absent('L'),
+                Absent('O'),
+                Absent('T')
+            ]
+        );
+    }
+
+    #[test]
+    fn check_partial_pads_and_flags_invalid_characters() {
+        use PartialTile::*;
+        assert_eq!(
+            check_partial("ca1"),
+            vec![Letter('C'), Letter('A'), Invalid('1'), Empty, Empty]
+        );
+        assert_eq!(check_partial("cigars").len(), WORD_LENGTH);
+    }
+
+    #[test]
+    fn pattern_for_spells_the_pattern_a_guess_shows() {
+        assert_eq!(pattern_for("CIGAR", "CAIRN").unwrap(), "GYYYB");
+        assert_eq!(pattern_for("cigar", "cigar").unwrap(), "GGGGG");
+        assert_eq!(pattern_for("apple", "allot").unwrap(), "GYBBB");
+        assert!(matches!(
+            pattern_for("cigar", "zzzzz"),
+            Err(WordleError::UnknownWord { .. })
+        ));
+        assert!(matches!(
+            pattern_for("cigars", "cairn"),
+            Err(WordleError::InvalidLength { .. })
+        ));
+    }
+
+    #[test]
+    fn checked_scoring_turns_bad_bytes_into_errors() {
+        assert_eq!(
+            try_score_n::<5>("CAF\u{c9}", "CAIRN"),
+            Err(WordleError::NonAlphabetic {
+                word: "CAF\u{c9}".into()
+            })
+        );
+        assert_eq!(
+            try_score_n::<5>("CIGAR", "cairn"),
+            Err(WordleError::NonAlphabetic {
+                word: "cairn".into()
+            })
+        );
+        assert_eq!(
+            try_score_n::<5>("CIGAR", "CAIRNS"),
+            Err(WordleError::InvalidLength {
+                expected: 5,
+                found: 6
+            })
+        );
+        assert_eq!(
+            try_score_n::<5>("CIGAR", "CAIRN"),
+            Ok(score("CIGAR", "CAIRN"))
+        );
+    }
+
+    #[test]
+    fn malformed_rows_rule_words_out_instead_of_panicking() {
+        let row = GuessResult {
+            guess: "CR\u{c9}NE".into(),
+            letters: score("CIGAR", "CRANE"),
+            lie_indices: Vec::new(),
+        };
+        for mode in [GameMode::Wordle, GameMode::Fibble] {
+            let mut game = Wordle::new_with_mode("cigar", mode).unwrap();
+            game.guesses.push(row.clone());
+            assert!(remaining_secrets(&game).is_empty());
+            assert_eq!(
+                explain_elimination(&game, "cigar"),
+                Err(WordleError::NonAlphabetic {
+                    word: "CR\u{c9}NE".into()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn score_guess_matches_internal_scoring() {
+        assert_eq!(
+            score_guess("apple", "allot").unwrap(),
+            score("APPLE", "ALLOT")
+        );
+        assert!(matches!(
+            score_guess("apple", "zzzzz"),
+            Err(WordleError::UnknownWord { .. })
+        ));
+        assert!(matches!(
+            score_guess("apples", "allot"),
+            Err(WordleError::InvalidLength { .. })
+        ));
+    }
+
+    #[test]
+    fn scoring_works_for_other_word_lengths() {
+        use LetterState::*;
+        assert_eq!(
+            score_n::<6>("BANANA", "NAMING"),
+            vec![
+                Present('N'),
+                Correct('A'),
+                Absent('M'),
+                Absent('I'),
+                Correct('N'),
+                Absent('G')
+            ]
+        );
+        assert_eq!(encode_pattern(&[PATTERN_CORRECT; 4]), pattern_space(4) - 1);
+        assert_eq!(pattern_code_to_string(pattern_space(6) - 1, 6), "GGGGGG");
+    }
+
+    #[test]
+    fn other_lengths_validate_against_their_own_lists() {
+        assert_eq!(
+            WordleN::<4>::new("cigar").unwrap_err(),
+            WordleError::InvalidLength {
+                expected: 4,
+                found: 5
+            }
+        );
+        assert!(allowed_words_of_length(4).is_empty());
+        assert!(matches!(
+            WordleN::<4>::new("cart"),
+            Err(WordleError::UnknownWord { .. })
+        ));
+    }
+
+    #[test]
+    fn records_history_and_detects_wins() {
+        let mut game = Wordle::new("cigar").unwrap();
+        assert!(!game.submit_guess("cairn").unwrap().is_correct());
+        assert!(game.submit_guess("cigar").unwrap().is_correct());
+        assert_eq!(game.guesses().len(), 2);
+    }
+
+    #[test]
+    fn to_record_summarizes_a_finished_game() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        game.submit_guess("cigar").unwrap();
+        let record = game.to_record(Duration::from_secs(42));
+        assert!(record.solved());
+        assert_eq!(record.attempts(), 2);
+        assert_eq!(record.guesses()[0], ("CAIRN".into(), "GYYYB".into()));
+    }
+
+    #[test]
+    fn from_history_keeps_scored_rows() {
+        let mut played = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        played.submit_guess("cairn").unwrap();
+        played.submit_guess("crane").unwrap();
+        let rebuilt =
+            Wordle::from_history("cigar", GameMode::Fibble, played.guesses().to_vec()).unwrap();
+        assert_eq!(rebuilt, played);
+    }
+
+    #[test]
+    fn from_history_rejects_long_histories_and_unknown_guesses() {
+        // Fibble allows more attempts, so it can produce a history too long for Wordle.
+        let mut played = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        for _ in 0..7 {
+            played.submit_guess("crane").unwrap();
+        }
+        assert_eq!(
+            Wordle::from_history("cigar", GameMode::Wordle, played.guesses().to_vec()).unwrap_err(),
+            WordleError::TooManyGuesses { max: 6, found: 7 }
+        );
+
+        let bogus = GuessResult {
+            guess: "ZZZZZ".into(),
+            letters: score("CIGAR", "ZZZZZ"),
+            lie_indices: Vec::new(),
+        };
+        assert!(matches!(
+            Wordle::from_history("cigar", GameMode::Wordle, vec![bogus]),
+            Err(WordleError::UnknownWord { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn guess_entropy_serializes_a_pattern_summary() {
+        let entropy = analyze_guess_against("crane", ["CIGAR", "REBUT", "CHAIR"]).unwrap();
+        let json = serde_json::to_value(&entropy).unwrap();
+        assert_eq!(json["guess"], "CRANE");
+        assert_eq!(json["total_secrets"], 3);
+        assert_eq!(json["distinct_patterns"], entropy.distinct_patterns());
+        assert_eq!(json["entropy_bits"], entropy.entropy_bits());
+        let patterns = json["patterns"].as_array().unwrap();
+        assert_eq!(patterns.len(), entropy.distinct_patterns());
+        assert!(patterns.contains(&serde_json::json!(["GYYBB", 1])));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn game_record_round_trips_through_json() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cigar").unwrap();
+        let record = game.to_record(Duration::from_secs(7));
+        let json = serde_json::to_string(&record).unwrap();
+        let restored: GameRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, record);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn game_state_round_trips_through_json() {
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        game.submit_guess("cairn").unwrap();
+        game.submit_guess("crane").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+        let restored: Wordle = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, game);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializing_revalidates_words() {
+        let json = r#"{"secret":"ZZZZZ","mode":"Wordle","lies":0,"guesses":[]}"#;
+        assert!(serde_json::from_str::<Wordle>(json).is_err());
+    }
+
+    #[test]
+    fn share_text_reports_attempts_and_emoji_rows() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        game.submit_guess("cigar").unwrap();
+        let text = game.share_text(true);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines, vec!["Wordle 2/6", "🟩🟨🟨🟨⬛", "🟩🟩🟩🟩🟩"]);
+        for row in &lines[1..] {
+            assert_eq!(row.chars().count(), WORD_LENGTH);
+        }
+        assert!(game.share_text(false).contains('⬜'));
+    }
+
+    #[test]
+    fn share_text_marks_unsolved_games() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        assert!(game.share_text(true).starts_with("Wordle X/6"));
+    }
+
+    #[test]
+    fn share_text_uses_the_games_attempt_limit() {
+        let mut game = Wordle::new("cigar").unwrap().with_max_attempts(10);
+        game.submit_guess("crane").unwrap();
+        game.submit_guess("cigar").unwrap();
+        assert!(game.share_text(true).starts_with("Wordle 2/10\n"));
+
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Fibble)
+            .unwrap()
+            .with_max_attempts(usize::MAX);
+        game.submit_guess("cigar").unwrap();
+        assert!(game.share_text(true).starts_with("Fibble 1/∞\n"));
+        assert_eq!(parse_emoji_grid(&game.share_text(true)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn parse_emoji_grid_round_trips_share_rows() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        game.submit_guess("cigar").unwrap();
+        let text = format!("{}  \n", game.share_text(false));
+        let rows = parse_emoji_grid(&text).unwrap();
+        assert_eq!(rows, vec![[2, 1, 1, 1, 0], [2, 2, 2, 2, 2]]);
+        assert_eq!(
+            rows[0],
+            compute_pattern_digits::<WORD_LENGTH>(b"CIGAR", b"CAIRN")
+        );
+    }
+
+    #[test]
+    fn parse_emoji_grid_rejects_short_rows() {
+        assert_eq!(
+            parse_emoji_grid("Wordle 1/6\n🟩🟩⬛🟨"),
+            Err(ParseError::WrongRowLength { line: 2, found: 4 })
+        );
+        assert_eq!(
+            parse_emoji_grid("🟩🟩x🟩🟩"),
+            Err(ParseError::UnknownSquare {
+                line: 1,
+                square: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn absurdle_host_keeps_the_largest_bucket() {
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Absurdle).unwrap();
+        assert_eq!(game.host_candidates().len(), secret_words().len());
+        let entropy = analyze_guess("crane").unwrap();
+        let largest = entropy.largest_bucket();
+
+        let row = game.submit_guess("crane").unwrap().clone();
+        assert_eq!(game.host_candidates().len(), largest);
+        assert!(!row.is_correct());
+        assert!(game
+            .host_candidates()
+            .iter()
+            .any(|word| word == game.secret()));
+        assert_eq!(score(game.secret(), "CRANE"), row.letters);
+        assert_eq!(remaining_secrets(&game).len(), largest);
+    }
+
+    #[test]
+    fn absurdle_concedes_once_one_candidate_remains() {
+        let list = WordList::from_lines(["abcde", "fghij"].into_iter()).unwrap();
+        let mut game = Wordle::new_with_lists("abcde", GameMode::Absurdle, &list).unwrap();
+        let first = game.submit_guess("abcde").unwrap().clone();
+        assert!(!first.is_correct());
+        assert_eq!(game.secret(), "FGHIJ");
+        assert!(game.submit_guess("fghij").unwrap().is_correct());
+    }
+
+    #[test]
+    fn hard_mode_rejects_dropped_greens() {
+        let mut game = Wordle::new_hard_mode("cigar", GameMode::Wordle).unwrap();
+        game.submit_guess("cairn").unwrap();
+        assert_eq!(
+            game.submit_guess("slate").unwrap_err(),
+            WordleError::HardModeViolation {
+                reason: String::from("1st letter must be C")
+            }
+        );
+        assert_eq!(
+            game.submit_guess("clasp").unwrap_err(),
+            WordleError::HardModeViolation {
+                reason: String::from("guess must contain I")
+            }
+        );
+        assert!(game.submit_guess("chair").is_ok());
+        assert_eq!(game.guesses().len(), 2);
+    }
+
+    #[test]
+    fn hard_mode_counts_duplicate_yellows() {
+        let mut game = Wordle::new_hard_mode("hello", GameMode::Wordle).unwrap();
+        // Both Ls of LLAMA are yellow against HELLO, so later guesses need two.
+        let row = game.submit_guess("llama").unwrap();
+        assert_eq!(
+            &row.letters()[..2],
+            &[LetterState::Present('L'), LetterState::Present('L')]
+        );
+        assert_eq!(
+            game.submit_guess("world").unwrap_err(),
+            WordleError::HardModeViolation {
+                reason: String::from("guess must contain 2 Ls")
+            }
+        );
+        assert!(game.submit_guess("hello").is_ok());
+    }
+
+    #[test]
+    fn letter_knowledge_keeps_the_best_state() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        assert_eq!(game.letter_knowledge()[&'A'], LetterState::Present('A'));
+        game.submit_guess("cigar").unwrap();
+        let knowledge = game.letter_knowledge();
+        assert_eq!(knowledge[&'A'], LetterState::Correct('A'));
+        assert_eq!(knowledge[&'N'], LetterState::Absent('N'));
+        assert_eq!(knowledge.len(), 6);
+    }
+
+    #[test]
+    fn game_mode_parses_case_insensitively() {
+        assert_eq!("FIBBLE".parse::<GameMode>(), Ok(GameMode::Fibble));
+        assert_eq!("wordle".parse::<GameMode>(), Ok(GameMode::Wordle));
+        let err = "foo".parse::<GameMode>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown mode: foo");
+    }
+
+    #[test]
+    fn pattern_strings_round_trip_every_code() {
+        for code in 0..PATTERN_SPACE {
+            let pattern = pattern_code_to_string(code, WORD_LENGTH);
+            assert_eq!(pattern_string_to_code(&pattern), Ok(code));
+        }
+        assert_eq!(
+            pattern_string_to_code("gybbb"),
+            Err(PatternError::InvalidChar { found: 'g' })
+        );
+        assert_eq!(
+            pattern_string_to_code("GYBB"),
+            Err(PatternError::WrongLength {
+                expected: WORD_LENGTH,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn expected_remaining_of_uniform_buckets_is_the_bucket_size() {
+        let mut pattern_counts = vec![0; PATTERN_SPACE];
+        pattern_counts[0] = 4;
+        pattern_counts[7] = 4;
+        pattern_counts[PATTERN_SPACE - 1] = 4;
+        let entropy = GuessEntropy {
+            guess: String::from("CRANE"),
+            pattern_counts,
+        };
+        assert_eq!(entropy.expected_remaining(), 4.0);
+        assert_eq!(entropy.probability_of("GGGGG"), Some(1.0 / 3.0));
+        assert_eq!(entropy.probability_of("GGGGY"), Some(0.0));
+        assert_eq!(entropy.probability_of("GGGG"), None);
+        assert_eq!(entropy.probability_of("ggggg"), None);
+    }
+
+    #[test]
+    fn full_distribution_covers_every_pattern_code() {
+        let entropy = analyze_guess("crane").unwrap();
+        let full = entropy.full_distribution();
+        assert_eq!(full.len(), PATTERN_SPACE);
+        assert_eq!(full.iter().sum::<usize>(), entropy.total_secrets());
+        assert!(full.contains(&0));
+
+        let (pattern, count) = entropy.most_likely_pattern();
+        assert_eq!(count, entropy.largest_bucket());
+        assert_eq!(full[pattern_string_to_code(&pattern).unwrap()], count);
+        assert_eq!(pattern, "BBBBB");
+    }
+
+    #[test]
+    fn merging_halves_matches_analyzing_the_whole() {
+        let secrets: Vec<&str> = secret_words().iter().map(String::as_str).collect();
+        let (front, back) = secrets.split_at(secrets.len() / 2);
+        let front = analyze_guess_against("crane", front.iter().copied()).unwrap();
+        let back = analyze_guess_against("crane", back.iter().copied()).unwrap();
+        let merged = front.merge(&back).unwrap();
+        let whole = analyze_guess("crane").unwrap();
+        assert_eq!(merged.full_distribution(), whole.full_distribution());
+        assert_eq!(merged.entropy_bits(), whole.entropy_bits());
+
+        let other = analyze_guess_against("slate", secrets.iter().copied()).unwrap();
+        assert_eq!(
+            front.merge(&other).unwrap_err(),
+            MergeError::GuessMismatch {
+                left: "CRANE".into(),
+                right: "SLATE".into()
+            }
+        );
+    }
+
+    #[test]
+    fn entropy_converts_between_bases() {
+        let entropy = analyze_guess("crane").unwrap();
+        let bits = entropy.entropy_bits();
+        assert!((entropy.entropy_nats() - bits * std::f64::consts::LN_2).abs() < 1e-12);
+        assert!((entropy.entropy_base(2.0) - bits).abs() < 1e-12);
+        assert!((entropy.entropy_base(10.0) - bits * std::f64::consts::LOG10_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn pattern_probabilities_sum_to_one() {
+        let entropy = analyze_guess("crane").unwrap();
+        let sum: f64 = entropy
+            .pattern_counts()
+            .iter()
+            .filter_map(|(pattern, _)| entropy.probability_of(pattern))
+            .sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn weighting_one_secret_lowers_entropy() {
+        let secrets = ["CIGAR", "REBUT", "SISSY", "HUMPH"];
+        let uniform =
+            analyze_guess_weighted("crane", secrets.iter().map(|secret| (*secret, 1.0))).unwrap();
+        let unweighted = analyze_guess_against("crane", secrets).unwrap();
+        assert!((uniform.entropy_bits() - unweighted.entropy_bits()).abs() < 1e-9);
+
+        let skewed = analyze_guess_weighted(
+            "crane",
+            secrets
+                .iter()
+                .map(|secret| (*secret, if *secret == "CIGAR" { 1000.0 } else { 1.0 })),
+        )
+        .unwrap();
+        assert!(skewed.entropy_bits() < 0.1);
+        assert!(skewed.entropy_bits() < uniform.entropy_bits());
+        assert_eq!(skewed.total_weight(), 1003.0);
+    }
+
+    #[test]
+    fn solved_games_are_over_and_refuse_guesses() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        assert_eq!(game.remaining_attempts(), 5);
+        assert!(!game.is_over());
+        game.submit_guess("cigar").unwrap();
+        assert!(game.is_solved());
+        assert!(game.is_over());
+        assert_eq!(game.remaining_attempts(), 0);
+        assert_eq!(game.submit_guess("crane"), Err(WordleError::AlreadySolved));
+        assert_eq!(game.guesses().len(), 2);
+    }
+
+    #[test]
+    fn exhausting_attempts_ends_the_game_unsolved() {
+        let mut game = Wordle::new("cigar").unwrap();
+        for _ in 0..game.max_attempts() {
+            game.submit_guess("crane").unwrap();
+        }
+        assert!(game.is_over());
+        assert!(!game.is_solved());
+        // The seventh Wordle guess is refused and not recorded.
+        assert_eq!(
+            game.submit_guess("cigar"),
+            Err(WordleError::OutOfAttempts { max: 6 })
+        );
+        assert_eq!(game.guesses().len(), 6);
+        assert_eq!(
+            game.submit_guess("zzzzz"),
+            Err(WordleError::OutOfAttempts { max: 6 })
+        );
+    }
+
+    #[test]
+    fn submit_and_check_reports_a_win_on_the_last_attempt() {
+        let mut game = Wordle::new("cigar").unwrap();
+        for _ in 1..game.max_attempts() {
+            let (_, outcome) = game.submit_and_check("crane").unwrap();
+            assert_eq!(outcome, Outcome::Ongoing);
+        }
+        let (row, outcome) = game.submit_and_check("cigar").unwrap();
+        assert!(row.is_correct());
+        assert_eq!(outcome, Outcome::Won { attempts: 6 });
+    }
+
+    #[test]
+    fn submit_and_check_reports_a_loss() {
+        let mut game = Wordle::new("cigar").unwrap();
+        for _ in 1..game.max_attempts() {
+            game.submit_guess("crane").unwrap();
+        }
+        let (_, outcome) = game.submit_and_check("crane").unwrap();
+        assert_eq!(
+            outcome,
+            Outcome::Lost {
+                secret: String::from("CIGAR")
+            }
+        );
+    }
+
+    #[test]
+    fn fibble_wins_despite_a_lied_row() {
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        let (row, outcome) = game.submit_and_check("cigar").unwrap();
+        assert!(!row.is_correct());
+        assert_eq!(outcome, Outcome::Won { attempts: 1 });
+    }
+
+    #[test]
+    fn daily_puzzles_follow_the_utc_date() {
+        let day = Duration::from_secs(SECONDS_PER_DAY);
+        let launch = UNIX_EPOCH + day * DAILY_EPOCH_DAYS as u32;
+        assert_eq!(daily_puzzle_number(launch), 0);
+        assert_eq!(
+            daily_puzzle_number(launch + day - Duration::from_secs(1)),
+            0
+        );
+        assert_eq!(daily_puzzle_number(launch + day * 100), 100);
+        assert_eq!(daily_puzzle_number(UNIX_EPOCH), 0);
+
+        assert_eq!(daily_secret(100), secret_words()[100]);
+        assert_eq!(
+            daily_secret(secret_words().len() as u64 + 7),
+            daily_secret(7)
+        );
+    }
+
+    #[test]
+    fn fingerprint_notices_edits_that_keep_counts() {
+        let allowed: Vec<String> = vec!["CIGAR".into(), "REBUT".into()];
+        let secrets: Vec<String> = vec!["CIGAR".into()];
+        let original = word_lists_fingerprint(&allowed, &secrets);
+        assert_eq!(original, word_lists_fingerprint(&allowed, &secrets));
+
+        let edited: Vec<String> = vec!["CIGAR".into(), "REBUS".into()];
+        assert_ne!(original, word_lists_fingerprint(&edited, &secrets));
+        // Moving a word between lists keeps the words but changes the lists.
+        assert_ne!(
+            original,
+            word_lists_fingerprint(&allowed[..1], &[allowed[1].clone(), secrets[0].clone()])
+        );
+        assert_eq!(
+            word_lists_hash(),
+            word_lists_fingerprint(allowed_words(), secret_words())
+        );
+    }
+
+    #[test]
+    fn colored_string_contains_ansi_sequences() {
+        let mut game = Wordle::new("cigar").unwrap();
+        let guess = game.submit_guess("cairn").unwrap();
+        let colored = guess.colored_string();
+        assert!(colored.contains("\x1b[")); // basic sanity check for ANSI output
+    }
+
+    #[test]
+    fn custom_theme_codes_appear_in_output() {
+        let mut game = Wordle::new("cigar").unwrap();
+        let guess = game.submit_guess("cairn").unwrap();
+        let theme = Theme {
+            correct: String::from("<c>"),
+            present: String::from("<p>"),
+            absent: String::from("<a>"),
+            extra_copy: None,
+        };
+        let colored = guess.colored_string_with_theme(&theme);
+        assert!(colored.starts_with("<c> C "));
+        assert!(colored.contains("<p> A "));
+        assert!(colored.contains("<a> N "));
+        assert_eq!(
+            guess.colored_string(),
+            guess.colored_string_with_theme(&Theme::default())
+        );
+        assert!(guess
+            .colored_string_with_theme(&Theme::high_contrast())
+            .contains(&Theme::high_contrast().correct));
+    }
+
+    #[test]
+    fn extra_copies_of_a_letter_can_be_marked() {
+        let mut game = Wordle::new("apple").unwrap();
+        let row = game.submit_guess("allot").unwrap().clone();
+        assert_eq!(row.letters()[2], LetterState::Absent('L'));
+        let flagged: Vec<usize> = (0..WORD_LENGTH)
+            .filter(|&position| row.is_extra_copy(position))
+            .collect();
+        // The first L is present, and O and T are simply not in the word.
+        assert_eq!(flagged, [2]);
+
+        let theme = Theme {
+            extra_copy: Some(String::from("<x>")),
+            ..Theme::default()
+        };
+        let colored = row.colored_string_with_theme(&theme);
+        assert!(colored.contains("<x> L "));
+        assert_eq!(colored.matches("<x>").count(), 1);
+        // Without the option the extra L is painted like any other absent tile.
+        assert!(!row.colored_string().contains("\x1b[48;5;136m"));
+        assert!(row
+            .colored_string_with_theme(&Theme::default().marking_extra_copies())
+            .contains("\x1b[48;5;136m"));
+    }
+
+    #[test]
+    fn plain_string_has_no_escape_codes() {
+        let mut game = Wordle::new("cigar").unwrap();
+        let guess = game.submit_guess("cairn").unwrap();
+        let plain = guess.plain_string();
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(plain, "[C] (A) (I) (R) n");
+    }
+
+    #[test]
+    fn remaining_secrets_keeps_actual_solution() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        let secrets = remaining_secrets(&game);
+        assert!(secrets.contains(&"CIGAR"));
+    }
+
+    #[test]
+    fn remaining_secrets_collapses_after_solution_found() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cigar").unwrap();
+        let secrets = remaining_secrets(&game);
+        assert_eq!(secrets, vec!["CIGAR"]);
+    }
+
+    #[test]
+    fn remaining_secrets_from_narrows_a_custom_pool() {
+        let pool: Vec<String> = ["CIGAR", "CRANE", "CHAIR", "VIGOR"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        let secrets = remaining_secrets_from(&game, pool.iter().map(|word| word.as_str()));
+        assert_eq!(secrets, vec!["CIGAR", "CHAIR"]);
+
+        // Lowercase words still match; malformed ones are skipped rather than scored.
+        let messy = ["chair", "CIGARS", "CIG", "C\u{c9}GAR", "vigor"];
+        assert_eq!(remaining_secrets_from(&game, messy), vec!["chair"]);
+    }
+
+    #[test]
+    fn expected_reduction_fraction_spans_no_help_to_nearly_everything() {
+        let single = analyze_guess_against("crane", ["CIGAR"]).unwrap();
+        assert_eq!(single.expected_reduction_fraction(), 0.0);
+
+        // CIGAR shows a different pattern for each of these, so it leaves one word of four.
+        let split = analyze_guess_against("cigar", ["CIGAR", "REBUT", "HUMPH", "CRANE"]).unwrap();
+        assert_eq!(split.distinct_patterns(), 4);
+        assert_eq!(split.expected_reduction_fraction(), 0.75);
+
+        let opener = analyze_guess("soare").unwrap();
+        assert!(opener.expected_reduction_fraction() > 0.95);
+        assert!(opener.expected_reduction_fraction() < 1.0);
+    }
+
+    #[test]
+    fn entropy_bits_ignores_zero_probabilities() {
+        let entropy = analyze_guess_against("cigar", vec!["CIGAR"]).unwrap();
+        assert_eq!(entropy.total_secrets(), 1);
+        assert!(entropy.entropy_bits().is_finite());
+        assert_eq!(entropy.entropy_bits(), 0.0);
+    }
+
+    #[test]
+    fn expected_guesses_shrink_as_the_game_narrows() {
+        let mut game = Wordle::new("cigar").unwrap();
+        let mut estimates = Vec::new();
+        for guess in ["crane", "moist", "cigar"] {
+            game.submit_guess(guess).unwrap();
+            estimates.push(expected_guesses_remaining(&game));
+        }
+        assert!(
+            estimates.windows(2).all(|pair| pair[1] < pair[0]),
+            "{estimates:?}"
+        );
+        assert!(estimates[0] > 1.5, "{estimates:?}");
+        assert_eq!(estimates.last(), Some(&1.0));
+
+        assert_eq!(expected_guesses_from(0, 0.0), 1.0);
+        assert_eq!(expected_guesses_from(2, 0.0), 2.0);
+        assert!((expected_guesses_from(16, 2.0) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn information_gain_scores_a_chosen_guess_mid_game() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("slate").unwrap();
+        let candidates = remaining_secrets(&game);
+        assert!(candidates.len() > 1);
+
+        let gain = information_gain(&game, "crony").unwrap();
+        let expected = analyze_guess_against("CRONY", candidates.iter().copied())
+            .unwrap()
+            .entropy_bits();
+        assert_eq!(gain, expected);
+        assert!(gain > 0.0);
+        assert!(gain <= best_information_guess(&game).unwrap().entropy_bits());
+        assert!(matches!(
+            information_gain(&game, "zzzzz"),
+            Err(WordleError::UnknownWord { .. })
+        ));
+
+        game.submit_guess("cigar").unwrap();
+        assert_eq!(information_gain(&game, "crony"), Ok(0.0));
+    }
+
+    #[test]
+    fn suggestions_can_skip_played_words() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cigar").unwrap();
+        let options = SuggestOptions {
+            exclude_played: true,
+            ..SuggestOptions::default()
+        };
+
+        let best = best_information_guess_with(&game, &options).unwrap();
+        assert_ne!(best.guess(), "CIGAR");
+        let top = top_information_guesses_with(&game, 20, &options);
+        assert_eq!(top.len(), 20);
+        assert!(top.iter().all(|entropy| entropy.guess() != "CIGAR"));
+        // Without the option the played word is still ranked.
+        assert!(top_information_guesses(&game, usize::MAX)
+            .iter()
+            .any(|entropy| entropy.guess() == "CIGAR"));
+    }
+
+    #[test]
+    fn frequency_heuristic_prefers_common_positional_letters() {
+        let candidates = ["SLATE", "SLANT", "SLASH", "SLAIN", "STALE"];
+        let counts = positional_letter_counts(&candidates);
+        assert!(
+            positional_frequency_score("SLATE", &counts)
+                > positional_frequency_score("FUZZY", &counts)
+        );
+        // The repeated S in SASSY only scores once.
+        assert_eq!(positional_frequency_score("SASSY", &counts), 5);
+
+        let guess = frequency_heuristic_guess(&candidates).unwrap();
+        assert!(
+            guess.starts_with("SLA"),
+            "unexpected heuristic guess {guess}"
+        );
+        assert_eq!(frequency_heuristic_guess(&[]), None);
     }
-    let mut rng = thread_rng();
-    let lie_index = rng.gen_range(0..letters.len());
-    let original = letters[lie_index].clone();
-    letters[lie_index] = random_lie_state(&original, &mut rng);
-}
 
-fn random_lie_state(state: &LetterState, rng: &mut impl Rng) -> LetterState {
-    let letter = state.letter();
-    match state {
-        LetterState::Correct(_) => match rng.gen_range(0..2) {
-            0 => LetterState::Present(letter),
-            _ => LetterState::Absent(letter),
-        },
-        LetterState::Present(_) => match rng.gen_range(0..2) {
-            0 => LetterState::Correct(letter),
-            _ => LetterState::Absent(letter),
-        },
-        LetterState::Absent(_) => match rng.gen_range(0..2) {
-            0 => LetterState::Correct(letter),
-            _ => LetterState::Present(letter),
-        },
+    #[test]
+    fn positional_frequencies_sum_to_one_per_position() {
+        let frequencies = positional_frequencies(&["slate", "SLANT", "SLASH", "STALE"]);
+        for row in &frequencies {
+            assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(frequencies[0][(b'S' - b'A') as usize], 1.0);
+        assert_eq!(frequencies[1][(b'L' - b'A') as usize], 0.75);
+        assert_eq!(frequencies[1][(b'T' - b'A') as usize], 0.25);
+        assert_eq!(positional_frequencies(&[]), [[0.0; 26]; WORD_LENGTH]);
     }
-}
 
-/// Computes the entropy of a guess against every known secret word.
-pub fn analyze_guess(guess: &str) -> Result<GuessEntropy, WordleError> {
-    analyze_guess_against(guess, secret_words().iter().map(|word| word.as_str()))
-}
+    #[test]
+    fn undo_widens_the_remaining_secrets_again() {
+        let mut game = Wordle::new("cigar").unwrap();
+        assert_eq!(game.undo_last_guess(), None);
+        game.submit_guess("crane").unwrap();
+        let after_one = remaining_secrets(&game).len();
+        game.submit_guess("fight").unwrap();
+        assert!(remaining_secrets(&game).len() < after_one);
 
-/// Computes the entropy of a guess against an arbitrary list of secret candidates.
-pub fn analyze_guess_against<'a>(
-    guess: &str,
-    secrets: impl IntoIterator<Item = &'a str>,
-) -> Result<GuessEntropy, WordleError> {
-    let normalized_guess = normalize(guess)?;
-    ensure_allowed(&normalized_guess)?;
+        let undone = game.undo_last_guess().unwrap();
+        assert_eq!(undone.guess(), "FIGHT");
+        assert_eq!(game.guesses().len(), 1);
+        assert_eq!(remaining_secrets(&game).len(), after_one);
 
-    let mut pattern_counts = [0usize; PATTERN_SPACE];
-    let guess_bytes = normalized_guess.as_bytes();
-    for secret in secrets {
-        let digits = compute_pattern_digits(secret.as_bytes(), guess_bytes);
-        let pattern_code = encode_pattern(&digits);
-        pattern_counts[pattern_code] += 1;
+        game.submit_guess("cigar").unwrap();
+        assert!(game.is_over());
+        game.undo_last_guess();
+        assert_eq!(game.outcome(), Outcome::Ongoing);
     }
 
-    Ok(GuessEntropy {
-        guess: normalized_guess,
-        pattern_counts,
-    })
-}
+    #[test]
+    fn undo_restores_the_absurdle_hosts_candidates() {
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Absurdle).unwrap();
+        game.submit_guess("crane").unwrap();
+        let after_one = game.host_candidates().to_vec();
+        game.submit_guess("moist").unwrap();
+        assert!(game.host_candidates().len() < after_one.len());
 
-fn compute_pattern_digits(secret: &[u8], guess: &[u8]) -> [u8; WORD_LENGTH] {
-    debug_assert_eq!(
-        secret.len(),
-        WORD_LENGTH,
-        "secret words must be {WORD_LENGTH} letters long"
-    );
-    debug_assert_eq!(
-        guess.len(),
-        WORD_LENGTH,
-        "guess words must be {WORD_LENGTH} letters long"
-    );
+        game.undo_last_guess();
+        assert_eq!(game.host_candidates(), after_one.as_slice());
+        assert!(after_one.contains(&game.secret().to_string()));
+    }
 
-    let mut digits = [PATTERN_ABSENT; WORD_LENGTH];
-    let mut leftovers = [0u8; ALPHABET_SIZE];
+    #[test]
+    fn compare_guesses_picks_the_more_informative_word() {
+        let mut game = Wordle::new("cigar").unwrap();
+        let comparison = compare_guesses(&game, "fuzzy", "soare").unwrap();
+        assert_eq!(comparison.first().guess(), "FUZZY");
+        assert!(comparison.second().entropy_bits() > comparison.first().entropy_bits());
+        assert!(comparison.second().expected_remaining() < comparison.first().expected_remaining());
+        assert_eq!(comparison.winner().map(GuessEntropy::guess), Some("SOARE"));
 
-    for idx in 0..WORD_LENGTH {
-        let secret_byte = secret[idx];
-        let guess_byte = guess[idx];
-        if guess_byte == secret_byte {
-            digits[idx] = PATTERN_CORRECT;
-        } else {
-            leftovers[letter_index(secret_byte)] += 1;
-        }
+        game.submit_guess("crane").unwrap();
+        let tie = compare_guesses(&game, "moist", "MOIST").unwrap();
+        assert!(tie.winner().is_none());
+        assert!(matches!(
+            compare_guesses(&game, "moist", "zzzzz"),
+            Err(WordleError::UnknownWord { .. })
+        ));
     }
 
-    for idx in 0..WORD_LENGTH {
-        if digits[idx] == PATTERN_CORRECT {
-            continue;
-        }
+    #[test]
+    fn tracked_candidates_match_a_full_recount() {
+        use rand::{rngs::StdRng, SeedableRng};
 
-        let guess_byte = guess[idx];
-        let lookup = letter_index(guess_byte);
-        if leftovers[lookup] > 0 {
-            digits[idx] = PATTERN_PRESENT;
-            leftovers[lookup] -= 1;
+        let mut rng = StdRng::seed_from_u64(5);
+        for mode in [GameMode::Wordle, GameMode::Fibble, GameMode::Absurdle] {
+            let mut tracked = Wordle::new_with_mode("cigar", mode)
+                .unwrap()
+                .tracking_candidates();
+            let mut plain = Wordle::new_with_mode("cigar", mode).unwrap();
+            for guess in ["crane", "moist", "pudgy", "lobby"] {
+                let row = tracked
+                    .submit_guess_with_rng(guess, &mut rng)
+                    .unwrap()
+                    .clone();
+                // Replay the identical row so both games see the same lies.
+                plain = Wordle::from_history(
+                    "cigar",
+                    mode,
+                    [plain.guesses(), std::slice::from_ref(&row)].concat(),
+                )
+                .unwrap();
+                assert_eq!(
+                    remaining_secrets(&tracked),
+                    remaining_secrets(&plain),
+                    "{mode:?}"
+                );
+            }
+            assert_eq!(
+                remaining_secrets_count(&tracked),
+                remaining_secrets_count(&plain)
+            );
+
+            tracked.undo_last_guess();
+            tracked.undo_last_guess();
+            let replayed = Wordle::from_history("cigar", mode, tracked.guesses().to_vec()).unwrap();
+            assert_eq!(
+                remaining_secrets(&tracked),
+                remaining_secrets(&replayed),
+                "{mode:?}"
+            );
         }
     }
 
-    digits
-}
+    #[test]
+    fn remaining_secrets_iter_matches_the_vec() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        let collected = remaining_secrets(&game);
+        assert!(collected.len() > 1);
+        assert_eq!(remaining_secrets_iter(&game).collect::<Vec<_>>(), collected);
+        assert_eq!(remaining_secrets_count(&game), collected.len());
+        assert_eq!(
+            remaining_secrets_iter(&game).next(),
+            collected.first().copied()
+        );
+    }
 
-fn encode_pattern(digits: &[u8; WORD_LENGTH]) -> usize {
-    digits
-        .iter()
-        .fold(0usize, |acc, digit| acc * 3 + *digit as usize)
-}
-
-fn pattern_code_to_string(mut code: usize) -> String {
-    let mut chars = [b'B'; WORD_LENGTH];
-    for idx in (0..WORD_LENGTH).rev() {
-        let digit = code % 3;
-        code /= 3;
-        chars[idx] = match digit {
-            2 => b'G',
-            1 => b'Y',
-            _ => b'B',
-        };
+    #[test]
+    fn letter_states_match_scoring() {
+        // SLATE against STALE: S, A and E are in place, L and T are elsewhere.
+        let states = letter_states("slate", "gygyg").unwrap();
+        assert_eq!(states, score_guess("stale", "slate").unwrap());
+        assert_eq!(states[1], LetterState::Present('L'));
+        assert_eq!(
+            letter_states("slate", "GYB"),
+            Err(WordleError::InvalidPattern {
+                pattern: "GYB".into()
+            })
+        );
+        assert_eq!(
+            letter_states("slate", "GYXBB"),
+            Err(WordleError::InvalidPattern {
+                pattern: "GYXBB".into()
+            })
+        );
+        assert!(matches!(
+            letter_states("sl8te", "GYBBB"),
+            Err(WordleError::NonAlphabetic { .. })
+        ));
     }
-    chars.iter().map(|byte| char::from(*byte)).collect()
-}
 
-fn letter_index(letter: u8) -> usize {
-    debug_assert!(
-        letter.is_ascii_uppercase(),
-        "words should use only uppercase ASCII letters"
-    );
-    (letter - b'A') as usize
-}
+    #[test]
+    fn rendered_boards_have_a_line_per_attempt() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        let board = game.render_board();
+        assert_eq!(board.lines().count(), game.max_attempts());
+        assert_eq!(
+            board.lines().next(),
+            Some(game.guesses()[0].colored_string().as_str())
+        );
 
-fn secret_matches_history(secret: &str, game: &Wordle) -> bool {
-    match game.mode {
-        GameMode::Wordle => game
-            .guesses
-            .iter()
-            .all(|guess| score(secret, guess.guess()) == guess.letters),
-        GameMode::Fibble => fibble_history_matches(secret, game.guesses()),
+        let plain = game.render_board_plain();
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "[C] (R) (A) n e");
+        assert_eq!(lines[5], "_ _ _ _ _");
+
+        let endless = Wordle::new("cigar").unwrap().with_max_attempts(usize::MAX);
+        assert_eq!(endless.render_board_plain(), "");
     }
-}
 
-fn fibble_history_matches(secret: &str, guesses: &[GuessResult]) -> bool {
-    guesses
-        .iter()
-        .all(|guess| fibble_guess_matches(secret, guess))
-}
+    #[test]
+    fn html_and_svg_exports_color_every_tile() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        game.submit_guess("cigar").unwrap();
 
-fn fibble_guess_matches(secret: &str, guess: &GuessResult) -> bool {
-    let truth = score(secret, guess.guess());
-    let mismatches = truth
-        .iter()
-        .zip(guess.letters())
-        .filter(|(actual, reported)| *actual != *reported)
-        .count();
-    mismatches == 1
-}
+        let html = game.to_html();
+        assert_eq!(html.matches("<tr>").count(), 2);
+        assert_eq!(html.matches("<td style=\"background:#").count(), 10);
+        // CRANE against CIGAR: C correct, R and A present, N and E absent.
+        assert_eq!(html.matches("background:#6aaa64").count(), 6);
+        assert_eq!(html.matches("background:#c9b458").count(), 2);
+        assert_eq!(html.matches("background:#787c7e").count(), 2);
+        assert!(html.contains(">N</td>"));
 
-/// Returns the list of remaining possible secret words for the provided game state.
-pub fn remaining_secrets(game: &Wordle) -> Vec<&'static str> {
-    WORDLE_SECRET_LIST
-        .iter()
-        .map(|word| word.as_str())
-        .filter(|secret| secret_matches_history(secret, game))
-        .collect()
-}
+        let svg = game.to_svg();
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<rect ").count(), 10);
+        assert_eq!(escape_markup('<'), "&lt;");
+    }
 
-/// Returns the guess from the allowed list that maximizes the expected information gain.
-pub fn best_information_guess(game: &Wordle) -> Option<GuessEntropy> {
-    let candidates = remaining_secrets(game);
-    if candidates.is_empty() {
-        return None;
+    /// Checks `score_n::<N>` against [`score_reference`] on random pairs drawn from a small
+    /// alphabet, so repeated letters turn up in most words.
+    fn check_against_reference<const N: usize>(rng: &mut impl Rng, pairs: usize) {
+        let mut random_word = || -> String {
+            (0..N)
+                .map(|_| char::from(b'A' + rng.gen_range(0..5u8)))
+                .collect()
+        };
+        for _ in 0..pairs {
+            let secret = random_word();
+            let guess = random_word();
+            assert_eq!(
+                score_n::<N>(&secret, &guess),
+                score_reference(&secret, &guess),
+                "{guess} against {secret}"
+            );
+        }
     }
 
-    allowed_words()
-        .iter()
-        .filter_map(|guess| analyze_guess_against(guess, candidates.iter().copied()).ok())
-        .max_by(|a, b| {
-            a.entropy_bits()
-                .partial_cmp(&b.entropy_bits())
-                .unwrap_or(std::cmp::Ordering::Equal)
-        })
-}
+    #[test]
+    fn optimized_scoring_matches_the_reference_on_random_pairs() {
+        use rand::{rngs::StdRng, SeedableRng};
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut rng = StdRng::seed_from_u64(819);
+        check_against_reference::<4>(&mut rng, 5_000);
+        check_against_reference::<5>(&mut rng, 20_000);
+        check_against_reference::<6>(&mut rng, 5_000);
+        assert_eq!(score_reference("APPLE", "ALLOT"), score("APPLE", "ALLOT"));
+    }
 
     #[test]
-    fn rejects_words_not_in_wordle_list() {
+    fn secrets_only_suggestions_come_from_the_secret_list() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        let options = SuggestOptions {
+            suggest_from: SuggestPool::SecretsOnly,
+            ..SuggestOptions::default()
+        };
+        let best = best_information_guess_with(&game, &options).unwrap();
+        assert!(secret_words().iter().any(|word| word == best.guess()));
+        let top = top_information_guesses_with(&game, 10, &options);
+        assert_eq!(top.len(), 10);
+        assert!(top
+            .iter()
+            .all(|entry| secret_words().iter().any(|word| word == entry.guess())));
+
+        // The unrestricted pick is an obscure word, which is why the option exists.
+        let unrestricted = best_information_guess(&game).unwrap();
+        assert!(!secret_words()
+            .iter()
+            .any(|word| word == unrestricted.guess()));
+    }
+
+    #[test]
+    fn custom_attempt_limits_replace_the_mode_default() {
+        let mut game = Wordle::new("cigar").unwrap().with_max_attempts(2);
+        assert_eq!(game.max_attempts(), 2);
+        game.submit_guess("crane").unwrap();
+        game.submit_guess("slate").unwrap();
+        assert!(game.is_over());
         assert_eq!(
-            Wordle::new("zzzzz").unwrap_err(),
-            WordleError::UnknownWord {
-                word: "ZZZZZ".into()
-            }
+            game.submit_guess("cigar").unwrap_err(),
+            WordleError::OutOfAttempts { max: 2 }
         );
 
-        let mut game = Wordle::new("cigar").unwrap();
+        let mut endless = Wordle::new("cigar").unwrap().with_max_attempts(usize::MAX);
+        for guess in [
+            "crane", "slate", "pound", "fight", "mound", "brick", "jumpy",
+        ] {
+            endless.submit_guess(guess).unwrap();
+        }
+        assert!(!endless.is_over());
+        endless.submit_guess("cigar").unwrap();
+        assert_eq!(endless.outcome(), Outcome::Won { attempts: 8 });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saved_games_keep_a_custom_attempt_limit() {
+        let mut game = Wordle::new("cigar").unwrap().with_max_attempts(10);
+        for guess in [
+            "crane", "slate", "pound", "fight", "mound", "brick", "jumpy",
+        ] {
+            game.submit_guess(guess).unwrap();
+        }
+        let json = serde_json::to_string(&game).unwrap();
+        let restored: Wordle = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.max_attempts(), 10);
+        assert_eq!(restored.guesses().len(), 7);
+    }
+
+    #[test]
+    fn repeat_guard_rejects_replayed_words() {
+        let mut game = Wordle::new("cigar").unwrap().rejecting_repeats();
+        assert!(game.rejects_repeats());
+        game.submit_guess("crane").unwrap();
         assert_eq!(
-            game.submit_guess("zzzzz").unwrap_err(),
-            WordleError::UnknownWord {
-                word: "ZZZZZ".into()
+            game.submit_guess("Crane").unwrap_err(),
+            WordleError::RepeatedGuess {
+                word: "CRANE".into()
             }
         );
-    }
+        assert_eq!(game.guesses().len(), 1);
 
-    #[test]
-    fn rejects_words_of_incorrect_length() {
-        assert!(Wordle::new("tool").is_err());
-        let mut game = Wordle::new("cigar").unwrap();
-        assert!(game.submit_guess("longer").is_err());
+        let mut lenient = Wordle::new("cigar").unwrap();
+        lenient.submit_guess("crane").unwrap();
+        assert!(lenient.submit_guess("crane").is_ok());
     }
 
     #[test]
-    fn scores_guesses_with_duplicate_letters() {
-        let letters = score("APPLE", "ALLOT");
-        use LetterState::*;
+    fn lenient_games_accept_well_formed_unknown_guesses() {
+        let mut strict = Wordle::new("cigar").unwrap();
         assert_eq!(
-            letters,
-            vec![
-                Correct('A'),
-                Present('L'),
-                Absent('L'),
-                Absent('O'),
-                Absent('T')
-            ]
+            strict.submit_guess("qzxvj"),
+            Err(WordleError::UnknownWord {
+                word: "QZXVJ".into()
+            })
         );
+
+        let mut lenient = Wordle::new("cigar").unwrap().allowing_unknown_guesses();
+        assert!(lenient.allows_unknown_guesses());
+        let row = lenient.submit_guess("qzxvj").unwrap();
+        assert_eq!(row.guess(), "QZXVJ");
+        assert!(row.letters().iter().all(LetterState::is_absent));
+        // Shape is still checked, and the secret still has to be a real word.
+        assert!(matches!(
+            lenient.submit_guess("qz1vj"),
+            Err(WordleError::NonAlphabetic { .. })
+        ));
+        assert!(matches!(
+            lenient.submit_guess("qzx"),
+            Err(WordleError::InvalidLength { .. })
+        ));
+        assert!(Wordle::new("qzxvj").is_err());
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn records_history_and_detects_wins() {
-        let mut game = Wordle::new("cigar").unwrap();
-        assert!(!game.submit_guess("cairn").unwrap().is_correct());
-        assert!(game.submit_guess("cigar").unwrap().is_correct());
-        assert_eq!(game.guesses().len(), 2);
+    fn tracking_survives_a_json_round_trip() {
+        let mut game = Wordle::new("cigar").unwrap().tracking_candidates();
+        game.submit_guess("crane").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+        let restored: Wordle = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, game);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn colored_string_contains_ansi_sequences() {
-        let mut game = Wordle::new("cigar").unwrap();
-        let guess = game.submit_guess("cairn").unwrap();
-        let colored = guess.colored_string();
-        assert!(colored.contains("\x1b[")); // basic sanity check for ANSI output
+    fn lenient_games_round_trip_through_json() {
+        let mut game = Wordle::new("cigar").unwrap().allowing_unknown_guesses();
+        game.submit_guess("qzxvj").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+        let restored: Wordle = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, game);
+
+        // A hand-edited lowercase row is uppercased instead of reaching the scorer as is.
+        let edited: Wordle = serde_json::from_str(&json.replace("QZXVJ", "qzxvj")).unwrap();
+        assert_eq!(edited.guesses()[0].guess(), "QZXVJ");
+        assert_eq!(edited, game);
+        assert_eq!(remaining_secrets(&edited), remaining_secrets(&game));
     }
 
     #[test]
-    fn remaining_secrets_keeps_actual_solution() {
+    fn thresholded_guess_declines_when_nothing_is_left_to_learn() {
         let mut game = Wordle::new("cigar").unwrap();
-        game.submit_guess("cairn").unwrap();
-        let secrets = remaining_secrets(&game);
-        assert!(secrets.contains(&"CIGAR"));
+        game.submit_guess("cigar").unwrap();
+        assert!(best_information_guess(&game).is_some());
+        assert!(best_information_guess_thresholded(&game, 0.5).is_none());
     }
 
     #[test]
-    fn remaining_secrets_collapses_after_solution_found() {
+    fn minimax_guess_shrinks_the_worst_bucket_below_entropy_best() {
+        let pool = [
+            "FELLA", "AWASH", "CLOZE", "GATOR", "BEIGE", "KININ", "SPOKE", "GESTE", "TUBAL",
+            "TIARA", "CULLY", "WIDOW", "PIQUE", "DADDY", "PIECE", "CHERT", "GUMMA", "FUNKY",
+            "IDLER", "GULES", "SCOFF", "WHARF",
+        ];
+        let entropy_best = information_guess_among(&pool).unwrap();
+        let minimax_best = minimax_guess_among(&pool).unwrap();
+        assert_ne!(entropy_best.guess(), minimax_best.guess());
+        assert!(minimax_best.largest_bucket() < entropy_best.largest_bucket());
+        assert!(minimax_best.entropy_bits() <= entropy_best.entropy_bits());
+    }
+
+    #[test]
+    fn ranked_information_guesses_are_sorted_and_distinct() {
+        let pool = ["CIGAR", "CRANE", "CHAIR", "VIGOR", "SUGAR", "CEDAR"];
+        let ranked = ranked_information_guesses(&pool, 3);
+        assert_eq!(ranked.len(), 3);
+        for pair in ranked.windows(2) {
+            assert_ne!(pair[0].guess(), pair[1].guess());
+            assert!(pair[0].entropy_bits() >= pair[1].entropy_bits() - 1e-9);
+        }
+        let best = information_guess_among(&pool).unwrap();
+        assert_eq!(ranked[0].guess(), best.guess());
+    }
+
+    #[test]
+    fn entropy_ties_go_to_a_possible_secret() {
+        // Against these two, every guess scores either 0 or 1 bit. CIGAR and VIGOR split them
+        // and could win; earlier words such as ABACK split them too but cannot.
+        let pool = ["CIGAR", "VIGOR"];
+        let best = information_guess_among(&pool).unwrap();
+        assert_eq!(best.guess(), "CIGAR");
+        assert_eq!(best.entropy_bits(), 1.0);
+        let ranked = ranked_information_guesses(&pool, 2);
+        assert_eq!(ranked[0].guess(), "CIGAR");
+        assert_eq!(ranked[1].guess(), "VIGOR");
+
+        // Among equally informative non-candidates, the alphabetically first wins.
+        let outsiders = information_guess_excluding(&pool, allowed_words(), &pool).unwrap();
+        let first_splitter = allowed_words()
+            .iter()
+            .find(|word| {
+                analyze_guess_against(word, pool.iter().copied())
+                    .unwrap()
+                    .entropy_bits()
+                    == 1.0
+                    && !pool.contains(&word.as_str())
+            })
+            .unwrap();
+        assert_eq!(outsiders.guess(), first_splitter);
+    }
+
+    #[test]
+    fn explain_elimination_finds_the_first_contradicting_row() {
         let mut game = Wordle::new("cigar").unwrap();
-        game.submit_guess("cigar").unwrap();
-        let secrets = remaining_secrets(&game);
-        assert_eq!(secrets, vec!["CIGAR"]);
+        game.submit_guess("crane").unwrap();
+        game.submit_guess("moist").unwrap();
+        assert_eq!(explain_elimination(&game, "cigar"), Ok(None));
+
+        // CRANE showed C correct, which ROUTE lacks.
+        assert_eq!(
+            explain_elimination(&game, "route"),
+            Ok(Some(Elimination::Tile {
+                guess_index: 0,
+                position: 0,
+                shown: LetterState::Correct('C'),
+                actual: LetterState::Absent('C'),
+            }))
+        );
+        // CARET agrees with CRANE up to the E that CRANE showed absent.
+        assert_eq!(
+            explain_elimination(&game, "CARET"),
+            Ok(Some(Elimination::Tile {
+                guess_index: 0,
+                position: 4,
+                shown: LetterState::Absent('E'),
+                actual: LetterState::Present('E'),
+            }))
+        );
+        // CAROM survives CRANE but not MOIST, which showed M absent.
+        let elimination = explain_elimination(&game, "carom").unwrap().unwrap();
+        assert_eq!(elimination.guess_index(), 1);
+        assert!(explain_elimination(&game, "cigars").is_err());
+
+        let mut fibble = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        fibble.submit_guess("cigar").unwrap();
+        assert_eq!(explain_elimination(&fibble, "cigar"), Ok(None));
+        // Against MOIST the row would be wrong on at least four tiles, not one.
+        assert!(matches!(
+            explain_elimination(&fibble, "moist"),
+            Ok(Some(Elimination::LieCount { guess_index: 0, .. }))
+        ));
     }
 
     #[test]
-    fn entropy_bits_ignores_zero_probabilities() {
-        let entropy = analyze_guess_against("cigar", vec!["CIGAR"]).unwrap();
-        assert_eq!(entropy.total_secrets(), 1);
-        assert!(entropy.entropy_bits().is_finite());
-        assert_eq!(entropy.entropy_bits(), 0.0);
+    fn fibble_assist_keeps_the_true_answer() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        for guess in ["crane", "moist", "vigor"] {
+            game.submit_guess_with_rng(guess, &mut rng).unwrap();
+        }
+        // Rebuild the rows from what the player saw, without the recorded lie positions.
+        let rows: Vec<GuessResult> = game
+            .guesses()
+            .iter()
+            .map(|row| GuessResult::from_pattern(row.guess(), &row.pattern_string()).unwrap())
+            .collect();
+        assert!(rows.iter().all(|row| row.lie_indices().is_empty()));
+
+        let pool = secret_words().iter().map(String::as_str);
+        let remaining = fibble_remaining_from(pool, &rows);
+        assert!(remaining.contains(&"CIGAR"));
+        assert_eq!(remaining, remaining_secrets(&game));
+        assert!(remaining.len() < secret_words().len());
+        let messy = ["cigar", "CIGARS", "C\u{c9}GAR"];
+        assert_eq!(fibble_remaining_from(messy, &rows), ["cigar"]);
+
+        let (guess, worst) = best_fibble_guess(&remaining).unwrap();
+        assert!(allowed_words().contains(&guess));
+        assert!(worst <= remaining.len());
+        assert_eq!(best_fibble_guess(&[]), None);
+        assert!(matches!(
+            GuessResult::from_pattern("crane", "GYXBB"),
+            Err(WordleError::InvalidPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn seeded_rng_reproduces_fibble_lies() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let lie_positions = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut game = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+            ["cairn", "crane", "vigor"]
+                .iter()
+                .map(|guess| {
+                    let row = game.submit_guess_with_rng(guess, &mut rng).unwrap();
+                    let truth = score("CIGAR", row.guess());
+                    let lies: Vec<usize> = (0..WORD_LENGTH)
+                        .filter(|&idx| truth[idx] != row.letters()[idx])
+                        .collect();
+                    assert_eq!(lies.len(), 1);
+                    (lies[0], row.letters().to_vec())
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lie_positions(42), lie_positions(42));
+    }
+
+    #[test]
+    fn rows_record_where_fibble_lied() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut game = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        for guess in ["cairn", "crane", "vigor"] {
+            let row = game.submit_guess_with_rng(guess, &mut rng).unwrap();
+            let truth = score("CIGAR", row.guess());
+            let lie = (0..WORD_LENGTH).find(|&idx| truth[idx] != row.letters()[idx]);
+            assert_eq!(row.lie_index(), lie);
+            assert!(lie.is_some());
+        }
+
+        // Wordle never lies, so its rows have no lie to point at.
+        let mut game = Wordle::new("cigar").unwrap();
+        assert_eq!(game.submit_guess("cairn").unwrap().lie_index(), None);
     }
 
     #[test]
@@ -572,19 +4407,80 @@ mod tests {
                 LetterState::Correct('A'),
                 LetterState::Present('R'),
             ],
+            lie_indices: vec![4],
         });
         let secrets = remaining_secrets(&game);
         assert!(secrets.contains(&"CIGAR"));
         assert!(!secrets.contains(&"TIGAR"));
     }
-}
 
-/// Returns the uppercase list of allowed Wordle guesses.
-pub fn allowed_words() -> &'static [String] {
-    WORDLE_ALLOWED_LIST.as_slice()
-}
+    #[test]
+    fn two_lie_fibble_keeps_the_secret_consistent() {
+        use rand::{rngs::StdRng, SeedableRng};
 
-/// Returns the uppercase list of canonical Wordle solutions.
-pub fn secret_words() -> &'static [String] {
-    WORDLE_SECRET_LIST.as_slice()
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut game = Wordle::new_fibble_with_lies("cigar", 2).unwrap();
+        for guess in ["cairn", "crane", "vigor", "sugar"] {
+            let row = game.submit_guess_with_rng(guess, &mut rng).unwrap();
+            assert_eq!(row.lie_indices().len(), 2);
+            assert_ne!(row.lie_indices()[0], row.lie_indices()[1]);
+        }
+        assert!(remaining_secrets(&game).contains(&"CIGAR"));
+
+        let mut one_lie = Wordle::new_with_mode("cigar", GameMode::Fibble).unwrap();
+        one_lie.guesses = game.guesses.clone();
+        assert!(!remaining_secrets(&one_lie).contains(&"CIGAR"));
+    }
+
+    #[test]
+    fn fibble_lie_count_cannot_exceed_word_length() {
+        assert_eq!(
+            Wordle::new_fibble_with_lies("cigar", 6).unwrap_err(),
+            WordleError::TooManyLies { lies: 6, max: 5 }
+        );
+    }
+
+    #[test]
+    fn guess_constraint_overlap_classifies_each_tile() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        use Overlap::*;
+        assert_eq!(
+            guess_constraint_overlap(&game, "cynic").unwrap(),
+            vec![
+                SatisfiesGreen,
+                Neutral,
+                WastesKnownAbsent,
+                ReusesYellow,
+                Neutral
+            ]
+        );
+    }
+
+    #[test]
+    fn confirmation_guess_isolates_the_suspect() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cairn").unwrap();
+        let guess = confirmation_guess(&game, "cigar").unwrap();
+        let candidates = remaining_secrets(&game);
+        let suspect_pattern = score("CIGAR", guess);
+        let lookalikes = candidates
+            .iter()
+            .filter(|candidate| **candidate != "CIGAR")
+            .filter(|candidate| score(candidate, guess) == suspect_pattern)
+            .count();
+        assert_eq!(lookalikes, 0);
+    }
+
+    #[test]
+    fn confirmation_guess_rejects_ruled_out_suspects() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cigar").unwrap();
+        assert_eq!(
+            confirmation_guess(&game, "crane").unwrap_err(),
+            WordleError::NotACandidate {
+                word: "CRANE".into()
+            }
+        );
+    }
 }
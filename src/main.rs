@@ -1,27 +1,68 @@
-use dirs::cache_dir;
 use fibble::{
-    allowed_words, analyze_guess_against, remaining_secrets, secret_words, GameMode, Wordle,
-    WordleError, WORD_LENGTH,
+    allowed_words, analyze_all_guesses, analyze_guess_against, best_fibble_guess, cache_dir_path,
+    compare_guess_scores, daily_puzzle_number, daily_secret, expected_guesses_from,
+    explain_elimination, fibble_remaining_from, information_gain, nearest_allowed,
+    remaining_secrets, remaining_secrets_count, secret_words, word_lists_hash, Constraints,
+    Elimination, GameMode, GuessEntropy, GuessResult, LetterState, OpeningBook, Outcome,
+    PatternMatrix, Wordle, WordleError, CACHE_DIR_ENV, OPENING_BOOK_FILE, WORD_LENGTH,
 };
 use indicatif::{ProgressBar, ProgressStyle};
-use rand::{seq::SliceRandom, thread_rng};
+use once_cell::sync::Lazy;
+use rand::rngs::StdRng;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::env;
 use std::error::Error;
 use std::fs::{self, File};
-use std::io::{self, Write};
-use std::path::PathBuf;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-const WORDLE_MAX_ATTEMPTS: usize = 6;
-const FIBBLE_MAX_ATTEMPTS: usize = 9;
-const FIRST_GUESS_CACHE_VERSION: u32 = 1;
+const FIRST_GUESS_CACHE_VERSION: u32 = 4;
 const FIRST_GUESS_CACHE_FILE: &str = "first_guess_entropies.json";
+const STATS_FILE: &str = "stats.json";
+/// How many secrets the `list` command prints before summarizing the rest.
+const LIST_LIMIT: usize = 50;
+
+/// Patterns for the bundled lists, built by the first suggestion and reused for the session.
+static PATTERN_MATRIX: Lazy<PatternMatrix> = Lazy::new(PatternMatrix::build);
 
 struct Config {
     mode: GameMode,
-    secret: String,
+    secret: Option<String>,
+    seed: Option<u64>,
+    daily: bool,
+    color: bool,
+    assist: bool,
+    /// A first guess to play automatically in any mode, from `--opener`.
+    opener: Option<String>,
+    /// Skips Fibble's random automatic first guess (`--no-opener`).
+    no_opener: bool,
+    /// Overrides the mode's attempt limit; `usize::MAX` for `--endless`.
+    max_attempts: Option<usize>,
+    /// A replay file to score without prompting, from `--replay`.
+    replay: Option<PathBuf>,
+    /// Rates each of the player's guesses against the best available (off with `--no-coach`).
+    coach: bool,
+    /// Suggests a guess before every turn (off with `--no-hints`, which also silences the
+    /// coach, since it names the best guess).
+    hints: bool,
+    /// Prints the saved statistics and exits (`--stats`).
+    show_stats: bool,
+    /// Clears the saved statistics and exits (`--reset-stats`).
+    reset_stats: bool,
+    /// Accepts any five-letter guess, not just allowed words (`--allow-unknown`).
+    allow_unknown: bool,
+    /// Leaves the game out of the statistics, so a finished daily puzzle can be replayed
+    /// (`--practice`).
+    practice: bool,
+    /// Where the first-guess cache and opening book live; `None` when caching is off or no
+    /// directory is known.
+    cache_dir: Option<PathBuf>,
+    /// Where statistics live: the same directory as the cache, but kept with `--no-cache`, so
+    /// turning caching off never loses the record or the daily-puzzle guard.
+    state_dir: Option<PathBuf>,
 }
 
 fn main() {
@@ -33,28 +74,133 @@ fn main() {
 
 fn run() -> Result<(), Box<dyn Error>> {
     let config = parse_args()?;
-    let mut game = Wordle::new_with_mode(&config.secret, config.mode)?;
-    let max_attempts = max_attempts(config.mode);
+    if config.show_stats || config.reset_stats {
+        let dir = config.state_dir.as_deref().ok_or_else(|| {
+            String::from("no directory is known for statistics; pass --cache-dir DIR")
+        })?;
+        if config.reset_stats {
+            return reset_stats(dir);
+        }
+        print_stats(&load_stats(dir));
+        return Ok(());
+    }
+    if let Some(path) = &config.replay {
+        return run_replay(path, config.color);
+    }
+    if config.assist {
+        if config.mode == GameMode::Fibble {
+            return run_fibble_assist();
+        }
+        return run_assist(config.cache_dir.as_deref());
+    }
+    // The daily puzzle seeds Fibble's lies from its number, so everyone sees the same lies.
+    // The number is fixed here, so a game that runs past midnight is recorded for the day it
+    // started.
+    let daily = config.daily.then(|| daily_puzzle_number(SystemTime::now()));
+    if let Some(number) = daily
+        && !config.practice
+        && let Some(dir) = config.state_dir.as_deref()
+        && load_stats(dir)
+            .for_mode(config.mode)
+            .completed_daily(number)
+    {
+        return Err(format!(
+            "you already finished daily puzzle #{number}; come back tomorrow, or add --practice \
+             to play it again without recording it"
+        )
+        .into());
+    }
+    // One generator drives both the random secret and Fibble's lies, so a seed replays both.
+    let mut rng = match config.seed.or(daily) {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let secret = match (&config.secret, daily) {
+        (Some(secret), _) => secret.clone(),
+        (None, Some(number)) => daily_secret(number).to_string(),
+        (None, None) => loop {
+            let secret = random_secret(&mut rng);
+            if config.opener.as_deref() != Some(secret.as_str()) {
+                break secret;
+            }
+        },
+    };
+    if let Some(opener) = &config.opener
+        && opener.eq_ignore_ascii_case(&secret)
+    {
+        // Saying why would give the daily answer away.
+        if daily.is_some() {
+            return Err(
+                format!("the opener {opener} cannot be played today; pick another opener").into(),
+            );
+        }
+        return Err(format!("the opener {opener} is the secret; pick another opener").into());
+    }
+    let mut game = Wordle::new_with_mode(&secret, config.mode)?
+        .rejecting_repeats()
+        .tracking_candidates();
+    if let Some(max_attempts) = config.max_attempts {
+        game = game.with_max_attempts(max_attempts);
+    }
+    if config.allow_unknown {
+        game = game.allowing_unknown_guesses();
+    }
+    let endless = game.max_attempts() == usize::MAX;
+    let book = config.cache_dir.as_deref().and_then(load_opening_book);
+    let max_attempts = game.max_attempts();
 
     println!("Welcome to Fibble!");
+    if let Some(number) = daily {
+        println!("Daily puzzle #{number}");
+    }
+    if endless {
+        println!("Try to guess the {WORD_LENGTH}-letter word; there is no attempt limit.");
+    } else {
+        println!("Try to guess the {WORD_LENGTH}-letter word in {max_attempts} attempts.");
+    }
     println!(
-        "Try to guess the {WORD_LENGTH}-letter word in {max_attempts} attempts. Type 'quit' to exit."
+        "Commands: 'list' or 'count' the remaining secrets, 'why WORD' to see what ruled a word \
+         out, 'undo' a guess, or 'quit'. Type 'guess COUNT' to play a word that is also a \
+         command."
     );
     if config.mode == GameMode::Fibble {
-        println!("Fibble mode: expect one lied tile per guess, and enjoy the automatic opener.");
+        println!("Fibble mode: expect one lied tile per guess.");
+    }
+    if config.mode == GameMode::Absurdle {
+        println!("Absurdle mode: the host dodges your guesses for as long as it can.");
     }
     println!();
 
-    if config.mode == GameMode::Fibble {
-        perform_fibble_auto_guess(&mut game)?;
+    // Fibble opens with a random word unless told otherwise; other modes only on request.
+    let opener = match &config.opener {
+        Some(opener) => Some(opener.clone()),
+        None if config.mode == GameMode::Fibble && !config.no_opener => {
+            Some(random_opener(&game, &mut rng))
+        }
+        None => None,
+    };
+    if let Some(opener) = opener {
+        println!("Automatic opener: {opener}");
+        let row = game.submit_guess_with_rng(&opener, &mut rng)?;
+        println!("{}", render_row(row, config.color));
+        if game.is_over() {
+            announce_result(&game, &config, daily);
+            return Ok(());
+        }
     }
 
-    while game.guesses().len() < max_attempts {
-        let analysis = best_guess_with_progress(&game);
-        print_guess_summary("Suggested guess", &analysis);
+    loop {
+        let analysis = turn_insights(&game, &config, book.as_ref());
+        if let Some(analysis) = &analysis {
+            print_guess_summary("Suggested guess", analysis);
+        }
 
         let attempt = game.guesses().len() + 1;
-        print!("Guess {attempt}/{max_attempts}: ");
+        if endless {
+            print!("Guess {attempt}: ");
+        } else {
+            print!("Guess {attempt}/{max_attempts}: ");
+        }
         io::stdout().flush()?;
 
         let mut line = String::new();
@@ -63,47 +209,518 @@ fn run() -> Result<(), Box<dyn Error>> {
             return Ok(());
         }
 
-        let guess = line.trim();
-        if guess.eq_ignore_ascii_case("quit") {
-            println!("Come back soon!");
-            return Ok(());
-        }
+        // Commands are checked before anything is treated as a guess; `guess WORD` plays a
+        // word such as COUNT that would otherwise read as a command.
+        let input = line.trim();
+        let command = input.to_ascii_lowercase();
+        let guess = match command.as_str() {
+            "quit" => {
+                println!("Come back soon!");
+                return Ok(());
+            }
+            "undo" => {
+                match game.undo_last_guess() {
+                    Some(row) => println!("Took back {}.", row.guess()),
+                    None => println!("There is no guess to take back."),
+                }
+                continue;
+            }
+            "list" => {
+                print_remaining(&game);
+                continue;
+            }
+            "count" => {
+                let count = remaining_secrets_count(&game);
+                println!(
+                    "{count} possible secret{} left.",
+                    if count == 1 { "" } else { "s" }
+                );
+                continue;
+            }
+            _ if command.starts_with("why ") => {
+                explain_word(&game, input["why ".len()..].trim());
+                continue;
+            }
+            _ => match command.strip_prefix("guess ") {
+                Some(_) => input["guess ".len()..].trim(),
+                None => input,
+            },
+        };
 
         if guess.chars().count() != WORD_LENGTH {
             println!("Please enter a {WORD_LENGTH}-letter word.");
             continue;
         }
 
-        let secret_word = game.secret().to_string();
-        match game.submit_guess(guess) {
+        // Scored before submitting, against the candidates the player was choosing among.
+        let coaching = analysis
+            .as_ref()
+            .filter(|_| config.coach)
+            .and_then(|analysis| coach_line(&game, guess, analysis));
+        match game.submit_guess_with_rng(guess, &mut rng) {
             Ok(row) => {
-                println!("{row}");
-                if row.guess() == secret_word {
-                    println!(
-                        "Nice! You solved it in {attempt} guess{}.",
-                        if attempt == 1 { "" } else { "es" }
-                    );
+                println!("{}", render_row(row, config.color));
+                if let Some(line) = coaching {
+                    println!("{line}");
+                }
+                if game.is_over() {
+                    announce_result(&game, &config, daily);
                     return Ok(());
                 }
             }
             Err(WordleError::InvalidLength { .. }) => {
                 println!("Please enter a {WORD_LENGTH}-letter word.");
             }
-            Err(WordleError::UnknownWord { .. }) => {
+            Err(WordleError::UnknownWord { word }) => {
                 println!("That's not one of the allowed Wordle guesses.");
+                let suggestions = nearest_allowed(&word, 2, 5);
+                if !suggestions.is_empty() {
+                    println!("Did you mean: {}?", suggestions.join(", "));
+                }
             }
+            Err(err @ (WordleError::OutOfAttempts { .. } | WordleError::AlreadySolved)) => {
+                println!("{err}.");
+                return Ok(());
+            }
+            Err(err) => println!("{err}"),
         }
     }
+}
 
-    println!("Out of guesses! The word was {}.", game.secret());
+/// Rates `guess` against the suggested best guess, or `None` when the guess is invalid or at
+/// most one candidate remains, since there is nothing to teach then.
+fn coach_line(game: &Wordle, guess: &str, insights: &GuessInsights) -> Option<String> {
+    let best = insights.best_guess.as_ref()?;
+    if best.matching_secrets <= 1 {
+        return None;
+    }
+    let bits = information_gain(game, guess).ok()?;
+    let verdict = coach_verdict(bits, best.entropy_bits);
+    Some(format!(
+        "Coach: that was {verdict} ({bits:.2} bits vs {:.2} for {}).",
+        best.entropy_bits, best.word
+    ))
+}
+
+/// Grades a guess worth `bits` against the best guess's `best_bits`: within 0.05 bits is great,
+/// at least three quarters of the best is solid, and anything less is weak.
+fn coach_verdict(bits: f64, best_bits: f64) -> &'static str {
+    if bits >= best_bits - 0.05 {
+        "a great guess"
+    } else if bits >= best_bits * 0.75 {
+        "a solid guess"
+    } else {
+        "a weak guess"
+    }
+}
+
+/// Prints the secrets still consistent with the game, capped at [`LIST_LIMIT`].
+fn print_remaining(game: &Wordle) {
+    let remaining = remaining_secrets(game);
+    let shown = remaining.len().min(LIST_LIMIT);
+    println!("{}", remaining[..shown].join(", "));
+    if remaining.len() > shown {
+        println!(
+            "...and {} more ({} in all).",
+            remaining.len() - shown,
+            remaining.len()
+        );
+    }
+}
+
+/// Answers `why WORD`: prints which earlier row rules `word` out, or that it is still possible.
+fn explain_word(game: &Wordle, word: &str) {
+    let elimination = match explain_elimination(game, word) {
+        Ok(elimination) => elimination,
+        Err(err) => {
+            println!("{err}.");
+            return;
+        }
+    };
+    let word = word.to_ascii_uppercase();
+    let Some(elimination) = elimination else {
+        if secret_words().contains(&word) {
+            println!("{word} is still a possible secret.");
+        } else {
+            println!("{word} fits every row so far, but it is not one of the possible secrets.");
+        }
+        return;
+    };
+
+    println!("{}", elimination_message(game, &word, &elimination));
+}
+
+/// Spells out why `word` (uppercase) was ruled out by `elimination`, a row of `game`.
+fn elimination_message(game: &Wordle, word: &str, elimination: &Elimination) -> String {
+    let row = &game.guesses()[elimination.guess_index()];
+    let source = format!("guess {} ({})", elimination.guess_index() + 1, row.guess());
+    match elimination {
+        Elimination::Tile {
+            position,
+            shown,
+            actual,
+            ..
+        } => {
+            let letter = shown.letter();
+            let position = position + 1;
+            // How many copies of the letter the row showed as being in the word, and how many
+            // the word really has.
+            let shown_copies = row
+                .letters()
+                .iter()
+                .filter(|state| state.letter() == letter && !state.is_absent())
+                .count();
+            let word_copies = word.chars().filter(|ch| *ch == letter).count();
+            match shown {
+                LetterState::Correct(_) => format!(
+                    "{word} does not have {letter} in position {position}, which {source} marked \
+                     correct."
+                ),
+                LetterState::Present(_) if actual.is_correct() => format!(
+                    "{word} has {letter} in position {position}, but {source} marked it present \
+                     elsewhere."
+                ),
+                LetterState::Present(_) if word_copies == 0 => {
+                    format!("{word} does not contain {letter}, which {source} marked present.")
+                }
+                LetterState::Present(_) if word_copies < shown_copies => format!(
+                    "{word} has fewer than {shown_copies} {letter}s, but {source} showed \
+                     {shown_copies}."
+                ),
+                LetterState::Absent(_) if actual.is_correct() => format!(
+                    "{word} has {letter} in position {position}, which {source} marked absent."
+                ),
+                LetterState::Absent(_) if shown_copies == 0 => {
+                    format!("{word} contains {letter}, which {source} marked absent.")
+                }
+                LetterState::Absent(_) if word_copies > shown_copies => format!(
+                    "{word} has more than {shown_copies} {letter}, but {source} marked the \
+                     extra {letter} absent."
+                ),
+                // The word has the right number of copies, but scoring would pair them with
+                // different tiles of the row.
+                _ => format!(
+                    "Against {word}, {source} would show the {letter} in position {position} as \
+                     {}, not {}.",
+                    state_name(actual),
+                    state_name(shown)
+                ),
+            }
+        }
+        Elimination::LieCount { mismatches, .. } => {
+            let lies = game.lies_per_guess();
+            let allowed = if game.lies_are_optional() {
+                format!("at most {lies}")
+            } else {
+                format!("exactly {lies}")
+            };
+            format!(
+                "{word} would make {mismatches} tile{} of {source} wrong, but each Fibble row \
+                 lies about {allowed}.",
+                if *mismatches == 1 { "" } else { "s" }
+            )
+        }
+    }
+}
+
+fn state_name(state: &LetterState) -> &'static str {
+    match state {
+        LetterState::Correct(_) => "correct",
+        LetterState::Present(_) => "present",
+        LetterState::Absent(_) => "absent",
+    }
+}
+
+/// Prints how a finished game ended and, unless practicing, adds it to the saved statistics.
+/// `daily` is the puzzle number for `--daily` games.
+fn announce_result(game: &Wordle, config: &Config, daily: Option<u64>) {
+    let won_in = match game.outcome() {
+        Outcome::Won { attempts } => {
+            println!(
+                "Nice! You solved it in {attempts} guess{}.",
+                if attempts == 1 { "" } else { "es" }
+            );
+            Some(attempts)
+        }
+        Outcome::Lost { secret } => {
+            println!("Out of guesses! The word was {secret}.");
+            None
+        }
+        Outcome::Ongoing => return,
+    };
+    if !config.practice {
+        record_stats(config.state_dir.as_deref(), config.mode, won_in, daily);
+    }
+    share_result(game);
+}
+
+/// Prints the emoji share grid and, with the `clipboard` feature, copies it too.
+fn share_result(game: &Wordle) {
+    let text = game.share_text(true);
+    println!();
+    println!("{text}");
+    #[cfg(feature = "clipboard")]
+    if copy_to_clipboard(&text).is_ok() {
+        println!("Copied to the clipboard!");
+    }
+}
+
+/// Puts `text` on the system clipboard. Fails when there is no clipboard to reach, as on a
+/// headless machine or over SSH, leaving the printed grid to copy by hand.
+#[cfg(feature = "clipboard")]
+fn copy_to_clipboard(text: &str) -> Result<(), arboard::Error> {
+    arboard::Clipboard::new()?.set_text(text)
+}
+
+/// Suggests guesses for a game played elsewhere, narrowing candidates from the patterns the
+/// player reports.
+fn run_assist(cache_dir: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    println!("Assist mode: enter each guess you played and the pattern it showed.");
+    println!("Patterns use G (green), Y (yellow), and B (gray), e.g. GYBBB. Type 'quit' to exit.");
+    println!();
+
+    let mut constraints = Constraints::new();
+    let mut candidates: Vec<&str> = secret_words().iter().map(|word| word.as_str()).collect();
+    let mut first_turn = true;
+
+    loop {
+        print_guess_summary(
+            "Suggested guess",
+            &suggestions_for(&candidates, first_turn, cache_dir),
+        );
+
+        let guess = loop {
+            let Some(line) = prompt("Guess you played: ")? else {
+                return Ok(());
+            };
+            let guess = line.to_ascii_uppercase();
+            if guess.chars().count() != WORD_LENGTH {
+                println!("Please enter a {WORD_LENGTH}-letter word.");
+            } else if !allowed_words().contains(&guess) {
+                println!("That's not one of the allowed Wordle guesses.");
+            } else {
+                break guess;
+            }
+        };
+
+        let pattern = loop {
+            let Some(line) = prompt("Pattern shown: ")? else {
+                return Ok(());
+            };
+            let pattern = line.to_ascii_uppercase();
+            match constraints.clone().observe(&guess, &pattern) {
+                Ok(narrowed) => {
+                    constraints = narrowed;
+                    break pattern;
+                }
+                Err(err) => println!("{err}."),
+            }
+        };
+
+        if pattern.chars().all(|tile| tile == 'G') {
+            println!("Congratulations on the solve!");
+            return Ok(());
+        }
+
+        candidates = constraints.filter_words(candidates.into_iter());
+        first_turn = false;
+        if candidates.is_empty() {
+            println!("No words match those patterns; double-check what you entered.");
+            return Ok(());
+        }
+    }
+}
+
+/// Like [`run_assist`], for a Fibble game: every reported row is assumed to lie about exactly
+/// one tile, so candidates are only ruled out when no single lie explains a row.
+fn run_fibble_assist() -> Result<(), Box<dyn Error>> {
+    println!("Fibble assist mode: enter each guess you played and the pattern it showed.");
+    println!("Patterns use G (green), Y (yellow), and B (gray), e.g. GYBBB. Type 'quit' to exit.");
+    println!("Every pattern is assumed to have one lying tile, so even GGGGG may not be a win.");
+    println!();
+
+    let mut rows: Vec<GuessResult> = Vec::new();
+    let mut candidates: Vec<&str> = secret_words().iter().map(|word| word.as_str()).collect();
+
+    loop {
+        match candidates.as_slice() {
+            [only] => println!("The answer must be {only}."),
+            _ => {
+                if let Some((guess, worst)) = best_fibble_guess(&candidates) {
+                    println!(
+                        "Suggested guess: {guess} ({} possible secrets, at most {worst} left \
+                         after it)",
+                        candidates.len()
+                    );
+                }
+            }
+        }
+
+        let guess = loop {
+            let Some(line) = prompt("Guess you played: ")? else {
+                return Ok(());
+            };
+            let guess = line.to_ascii_uppercase();
+            if guess.chars().count() != WORD_LENGTH {
+                println!("Please enter a {WORD_LENGTH}-letter word.");
+            } else if !allowed_words().contains(&guess) {
+                println!("That's not one of the allowed Wordle guesses.");
+            } else {
+                break guess;
+            }
+        };
+
+        let row = loop {
+            let Some(line) = prompt("Pattern shown: ")? else {
+                return Ok(());
+            };
+            match GuessResult::from_pattern(&guess, &line) {
+                Ok(row) => break row,
+                Err(err) => println!("{err}."),
+            }
+        };
+
+        rows.push(row);
+        candidates = fibble_remaining_from(candidates, &rows);
+        if candidates.is_empty() {
+            println!(
+                "No words fit those patterns with one lie each; double-check what you entered."
+            );
+            return Ok(());
+        }
+    }
+}
+
+/// A game read from a `--replay` file.
+struct Replay {
+    secret: String,
+    mode: GameMode,
+    seed: u64,
+    /// Each guess with the 1-based line it came from, for error messages.
+    guesses: Vec<(usize, String)>,
+}
+
+/// Reads a replay file: `secret WORD`, optional `mode MODE` and `seed N` lines, then one guess
+/// per line. Blank lines and lines starting with `#` are skipped.
+fn parse_replay(text: &str) -> Result<Replay, String> {
+    let mut secret = None;
+    let mut mode = GameMode::Wordle;
+    let mut seed = 0;
+    let mut guesses = Vec::new();
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_number = idx + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let first = fields.next().expect("line is not empty");
+        let value = fields.next();
+        if let Some(extra) = fields.next() {
+            return Err(format!("line {line_number}: unexpected {extra:?}"));
+        }
+        match (first.to_ascii_lowercase().as_str(), value) {
+            ("secret", Some(value)) => secret = Some(value.to_string()),
+            ("mode", Some(value)) => {
+                mode = value
+                    .parse()
+                    .map_err(|err| format!("line {line_number}: {err}"))?;
+            }
+            ("seed", Some(value)) => {
+                seed = value.parse().map_err(|_| {
+                    format!("line {line_number}: invalid seed {value}; expected a whole number")
+                })?;
+            }
+            ("secret" | "mode" | "seed", None) => {
+                return Err(format!("line {line_number}: missing value for {first}"));
+            }
+            (_, None) => guesses.push((line_number, first.to_string())),
+            (_, Some(_)) => {
+                return Err(format!(
+                    "line {line_number}: expected a guess or a secret, mode, or seed line"
+                ));
+            }
+        }
+    }
+
+    let secret = secret.ok_or_else(|| String::from("replay file has no secret line"))?;
+    Ok(Replay {
+        secret,
+        mode,
+        seed,
+        guesses,
+    })
+}
+
+/// Scores every guess in a replay file without prompting. Fibble lies come from the file's
+/// seed, so the same file always prints the same rows.
+fn run_replay(path: &Path, color: bool) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    let replay = parse_replay(&text).map_err(|err| format!("{}: {err}", path.display()))?;
+    let mut game = Wordle::new_with_mode(&replay.secret, replay.mode)
+        .map_err(|err| format!("{}: secret: {err}", path.display()))?;
+    let mut rng = StdRng::seed_from_u64(replay.seed);
+
+    for (line_number, guess) in &replay.guesses {
+        let row = game
+            .submit_guess_with_rng(guess, &mut rng)
+            .map_err(|err| format!("{}: line {line_number}: {guess}: {err}", path.display()))?;
+        println!("{}", render_row(row, color));
+    }
+
+    match game.outcome() {
+        Outcome::Won { attempts } => println!(
+            "Solved in {attempts} guess{}.",
+            if attempts == 1 { "" } else { "es" }
+        ),
+        Outcome::Lost { secret } => println!("Out of guesses! The word was {secret}."),
+        Outcome::Ongoing => println!("Replay ended with the game still in progress."),
+    }
     Ok(())
 }
 
+/// Prints `label` and reads a trimmed line, or `None` on end of input or `quit`.
+fn prompt(label: &str) -> io::Result<Option<String>> {
+    print!("{label}");
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line)? == 0 {
+        println!("\nNo input detected, exiting.");
+        return Ok(None);
+    }
+    let line = line.trim();
+    if line.eq_ignore_ascii_case("quit") {
+        println!("Come back soon!");
+        return Ok(None);
+    }
+    Ok(Some(line.to_string()))
+}
+
 fn parse_args() -> Result<Config, Box<dyn Error>> {
     let args: Vec<String> = env::args().skip(1).collect();
     let mut idx = 0;
-    let mut mode = GameMode::Wordle;
+    let mut mode = None;
     let mut secret: Option<String> = None;
+    let mut assist = false;
+    let mut seed = None;
+    let mut daily = false;
+    let mut cache_dir_override = None;
+    let mut no_cache = false;
+    let mut opener: Option<String> = None;
+    let mut no_opener = false;
+    let mut max_attempts: Option<usize> = None;
+    let mut endless = false;
+    let mut replay = None;
+    let mut show_stats = false;
+    let mut coach = true;
+    let mut hints = true;
+    let mut color = ColorChoice::Auto;
+    let mut reset_stats = false;
+    let mut practice = false;
+    let mut allow_unknown = false;
 
     while idx < args.len() {
         let arg = &args[idx];
@@ -113,11 +730,88 @@ fn parse_args() -> Result<Config, Box<dyn Error>> {
                 std::process::exit(0);
             }
             "--mode" => {
+                idx += 1;
+                let value = args.get(idx).ok_or_else(|| {
+                    String::from("missing value for --mode (wordle, fibble, or absurdle)")
+                })?;
+                mode = Some(value.parse()?);
+            }
+            "--assist" => assist = true,
+            "--daily" => daily = true,
+            "--no-cache" => no_cache = true,
+            "--no-opener" => no_opener = true,
+            "--endless" => endless = true,
+            "--stats" => show_stats = true,
+            "--no-coach" => coach = false,
+            "--no-hints" => hints = false,
+            "--reset-stats" => reset_stats = true,
+            "--practice" => practice = true,
+            "--allow-unknown" => allow_unknown = true,
+            "--max-attempts" => {
                 idx += 1;
                 let value = args
                     .get(idx)
-                    .ok_or_else(|| String::from("missing value for --mode (wordle or fibble)"))?;
-                mode = parse_mode(value)?;
+                    .ok_or_else(|| String::from("missing value for --max-attempts"))?;
+                let limit: usize = value.parse().map_err(|_| {
+                    format!("invalid attempt limit: {value}; expected a whole number")
+                })?;
+                if limit == 0 {
+                    return Err(String::from("--max-attempts must be at least 1").into());
+                }
+                max_attempts = Some(limit);
+            }
+            "--opener" => {
+                idx += 1;
+                let value = args
+                    .get(idx)
+                    .ok_or_else(|| String::from("missing value for --opener"))?;
+                let word = value.to_ascii_uppercase();
+                if !allowed_words().contains(&word) {
+                    return Err(format!("--opener {value} is not an allowed guess").into());
+                }
+                opener = Some(word);
+            }
+            "--replay" => {
+                idx += 1;
+                let value = args
+                    .get(idx)
+                    .ok_or_else(|| String::from("missing value for --replay"))?;
+                replay = Some(PathBuf::from(value));
+            }
+            "--color" => {
+                idx += 1;
+                let value = args.get(idx).ok_or_else(|| {
+                    String::from("missing value for --color (auto, always, or never)")
+                })?;
+                color = match value.to_ascii_lowercase().as_str() {
+                    "auto" => ColorChoice::Auto,
+                    "always" => ColorChoice::Always,
+                    "never" => ColorChoice::Never,
+                    _ => {
+                        return Err(format!(
+                            "invalid color choice: {value}; expected auto, always, or never"
+                        )
+                        .into());
+                    }
+                };
+            }
+            "--cache-dir" => {
+                idx += 1;
+                let value = args
+                    .get(idx)
+                    .ok_or_else(|| String::from("missing value for --cache-dir"))?;
+                cache_dir_override = Some(PathBuf::from(value));
+            }
+            "--seed" => {
+                idx += 1;
+                let value = args
+                    .get(idx)
+                    .ok_or_else(|| String::from("missing value for --seed"))?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {value}; expected a whole number"))?,
+                );
             }
             "--secret" => {
                 idx += 1;
@@ -140,58 +834,152 @@ fn parse_args() -> Result<Config, Box<dyn Error>> {
         idx += 1;
     }
 
-    let selected_secret = secret.unwrap_or_else(random_secret);
+    if daily && secret.is_some() {
+        return Err(String::from("--daily picks its own secret; drop --secret").into());
+    }
+    if replay.is_some() && (secret.is_some() || daily) {
+        return Err(String::from("--replay reads its secret from the file; drop --secret").into());
+    }
+    if replay.is_some() && (mode.is_some() || seed.is_some() || max_attempts.is_some() || endless) {
+        return Err(String::from(
+            "--replay reads its mode and seed from the file; \
+             drop --mode, --seed, --max-attempts, and --endless",
+        )
+        .into());
+    }
+    if opener.is_some() && no_opener {
+        return Err(String::from("--opener and --no-opener cannot be combined").into());
+    }
+    if endless {
+        if max_attempts.is_some() {
+            return Err(String::from("--endless has no limit; drop --max-attempts").into());
+        }
+        max_attempts = Some(usize::MAX);
+    }
+
+    let state_dir = cache_dir_path(cache_dir_override);
     Ok(Config {
-        mode,
-        secret: selected_secret,
+        mode: mode.unwrap_or(GameMode::Wordle),
+        secret,
+        seed,
+        daily,
+        color: color.enabled(),
+        assist,
+        opener,
+        no_opener,
+        max_attempts,
+        replay,
+        coach,
+        hints,
+        show_stats,
+        reset_stats,
+        allow_unknown,
+        practice,
+        cache_dir: if no_cache { None } else { state_dir.clone() },
+        state_dir,
     })
 }
 
-fn parse_mode(value: &str) -> Result<GameMode, Box<dyn Error>> {
-    match value.to_ascii_lowercase().as_str() {
-        "wordle" => Ok(GameMode::Wordle),
-        "fibble" => Ok(GameMode::Fibble),
-        _ => Err(format!("unknown mode: {value}").into()),
+/// How `--color` decides whether rows are printed with ANSI colors.
+#[derive(Clone, Copy)]
+enum ColorChoice {
+    /// Color only when stdout is a terminal and `NO_COLOR` is unset.
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => io::stdout().is_terminal() && !no_color_requested(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
     }
 }
 
-fn max_attempts(mode: GameMode) -> usize {
-    match mode {
-        GameMode::Wordle => WORDLE_MAX_ATTEMPTS,
-        GameMode::Fibble => FIBBLE_MAX_ATTEMPTS,
+/// Follows the NO_COLOR convention: any non-empty value disables ANSI colors.
+fn no_color_requested() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+fn render_row(row: &GuessResult, color: bool) -> String {
+    if color {
+        row.colored_string()
+    } else {
+        row.plain_string()
     }
 }
 
-fn perform_fibble_auto_guess(game: &mut Wordle) -> Result<(), WordleError> {
-    let mut guess = random_secret();
-    while guess.eq_ignore_ascii_case(game.secret()) {
-        guess = random_secret();
+/// Picks a random secret-list word other than the secret, so the opener never wins outright.
+fn random_opener(game: &Wordle, rng: &mut impl Rng) -> String {
+    loop {
+        let guess = random_secret(rng);
+        if !guess.eq_ignore_ascii_case(game.secret()) {
+            return guess;
+        }
     }
-    println!("Automatic opener: {guess}");
-    let row = game.submit_guess(&guess)?;
-    println!("{row}");
-    Ok(())
 }
 
-fn random_secret() -> String {
+fn random_secret(rng: &mut impl Rng) -> String {
     secret_words()
-        .choose(&mut thread_rng())
+        .choose(rng)
         .expect("Word list is not empty")
         .clone()
 }
 
 fn print_usage() {
     println!("Play Wordle in the terminal.");
-    println!("Usage: fibble [--mode MODE] [--secret WORD]");
-    println!("Modes: 'wordle' (default) or 'fibble'.");
+    println!(
+        "Usage: fibble [--mode MODE] [--secret WORD | --daily] [--seed N] [--assist] \
+         [--opener WORD | --no-opener] [--max-attempts N | --endless] \
+         [--cache-dir DIR | --no-cache] [--replay FILE] [--stats | --reset-stats] \
+         [--color auto|always|never] [--no-coach] [--no-hints] \
+         [--practice] [--allow-unknown]"
+    );
+    println!("Modes: 'wordle' (default), 'fibble', or 'absurdle'.");
     println!("Without --secret a random secret word is selected.");
+    println!("--seed N replays the same random secret and Fibble lies on every run.");
+    println!("--daily plays today's shared puzzle, chosen from the UTC date, once per mode.");
+    println!(
+        "--practice leaves the game out of your statistics, so today's puzzle can be replayed."
+    );
+    println!("--opener WORD plays WORD as your first guess; Fibble otherwise opens at random.");
+    println!("--no-opener skips Fibble's automatic first guess.");
+    println!("--max-attempts N allows N guesses instead of the mode's usual limit.");
+    println!("--endless removes the attempt limit for practice.");
+    println!("--cache-dir DIR stores the first-guess cache in DIR (or set {CACHE_DIR_ENV}).");
+    println!(
+        "--no-cache recomputes first-guess suggestions instead of caching them; statistics are \
+         still kept."
+    );
+    println!("Run fibble-bookgen to precompute turn-two suggestions into the cache directory.");
+    println!("--replay FILE scores the secret and guesses listed in FILE without prompting.");
+    println!("--no-coach stops rating each guess against the best available one.");
+    println!("--no-hints hides the suggested guess and the coach, so the game just scores.");
+    println!("--allow-unknown accepts any {WORD_LENGTH}-letter guess, even if it is not a word.");
+    println!("--stats prints your wins, streaks, and guess distribution per mode.");
+    println!("--reset-stats clears those statistics.");
+    println!("--assist suggests guesses for a game played elsewhere from the patterns you saw.");
+    println!("With --mode fibble it allows for one lying tile in every pattern.");
+    println!("--color always|never overrides whether guesses are printed with ANSI colors.");
+    println!("By default colors are used only on a terminal and when NO_COLOR is unset.");
 }
 
 fn print_guess_summary(label: &str, insights: &GuessInsights) {
     if let Some(best) = &insights.best_guess {
         println!(
-            "{label}: {} ({} possible secrets, {:.2} bits of information)",
-            best.word, best.matching_secrets, best.entropy_bits
+            "{label}: {} ({} possible secrets, {:.2} bits of information, rules out ~{:.0}% on \
+             average)",
+            best.word,
+            best.matching_secrets,
+            best.entropy_bits,
+            best.reduction_fraction * 100.0
+        );
+        println!(
+            "Estimated guesses to solve: ~{:.1}",
+            expected_guesses_from(best.matching_secrets, best.entropy_bits)
         );
     } else {
         println!("{label}: (no remaining candidates)");
@@ -210,8 +998,62 @@ fn print_guess_summary(label: &str, insights: &GuessInsights) {
     }
 }
 
-fn best_guess_with_progress(game: &Wordle) -> GuessInsights {
+/// Ranks this turn's guesses for the suggestion and coach lines, or `None` with `--no-hints`,
+/// in which case nothing is computed and no progress bar is shown.
+fn turn_insights(
+    game: &Wordle,
+    config: &Config,
+    book: Option<&OpeningBook>,
+) -> Option<GuessInsights> {
+    config
+        .hints
+        .then(|| best_guess_with_progress(game, config.cache_dir.as_deref(), book))
+}
+
+fn best_guess_with_progress(
+    game: &Wordle,
+    cache_dir: Option<&Path>,
+    book: Option<&OpeningBook>,
+) -> GuessInsights {
     let candidates = remaining_secrets(game);
+    // Fibble's lied tiles would send the lookup to the wrong page of the book.
+    if game.mode() == GameMode::Wordle
+        && candidates.len() > 1
+        && let [opener] = game.guesses()
+        && let Some(follow_up) = book.and_then(|book| book.follow_up(opener))
+    {
+        return insights_from_book(follow_up, &candidates);
+    }
+    suggestions_for(&candidates, game.guesses().is_empty(), cache_dir)
+}
+
+/// Scores the book's follow-up and the candidates themselves, which skips the full scan of
+/// the allowed list.
+fn insights_from_book(follow_up: &str, candidates: &[&str]) -> GuessInsights {
+    let suggest = |word: &str| {
+        analyze_guess_against(word, candidates.iter().copied())
+            .ok()
+            .map(|entropy| GuessSuggestion::from_entropy(&entropy))
+    };
+
+    let live: HashSet<&str> = candidates.iter().copied().collect();
+    let mut top_secret_guesses: Vec<GuessSuggestion> =
+        candidates.iter().filter_map(|word| suggest(word)).collect();
+    top_secret_guesses.sort_by(|a, b| compare_guess_scores(b.score(), a.score(), &live));
+    top_secret_guesses.truncate(4);
+
+    GuessInsights {
+        best_guess: suggest(follow_up),
+        top_secret_guesses,
+    }
+}
+
+/// Ranks guesses against `candidates`, consulting the on-disk cache on the first turn.
+fn suggestions_for(
+    candidates: &[&str],
+    first_turn: bool,
+    cache_dir: Option<&Path>,
+) -> GuessInsights {
     match candidates.len() {
         0 => return GuessInsights::default(),
         1 => {
@@ -219,6 +1061,7 @@ fn best_guess_with_progress(game: &Wordle) -> GuessInsights {
             let suggestion = GuessSuggestion {
                 word: only,
                 entropy_bits: 0.0,
+                reduction_fraction: 0.0,
                 matching_secrets: 1,
             };
             return GuessInsights {
@@ -229,39 +1072,39 @@ fn best_guess_with_progress(game: &Wordle) -> GuessInsights {
         _ => {}
     }
 
-    if game.guesses().is_empty() {
+    if first_turn && let Some(dir) = cache_dir {
+        let path = dir.join(FIRST_GUESS_CACHE_FILE);
         let expected_total = candidates.len();
-        if let Some(entries) = load_first_guess_cache(expected_total) {
-            return insights_from_cache(&entries, &candidates);
+        if let Some(entries) = load_first_guess_cache(&path, expected_total) {
+            return insights_from_cache(&entries, candidates);
         }
 
         let GuessCalculation {
             insights,
             all_suggestions,
-        } = calculate_guess_suggestions(&candidates, true);
-        if let Some(all_suggestions) = all_suggestions {
-            if let Err(err) = write_first_guess_cache(all_suggestions, expected_total) {
-                eprintln!("Failed to cache first-guess entropies: {err}");
-            }
+        } = calculate_guess_suggestions(candidates, true);
+        if let Some(all_suggestions) = all_suggestions
+            && let Err(err) = write_first_guess_cache(&path, all_suggestions, expected_total)
+        {
+            eprintln!("Failed to cache first-guess entropies: {err}");
         }
         insights
     } else {
-        calculate_guess_suggestions(&candidates, false).insights
+        calculate_guess_suggestions(candidates, false).insights
     }
 }
 
 fn calculate_guess_suggestions(candidates: &[&str], collect_all: bool) -> GuessCalculation {
-    let allowed = allowed_words();
     let candidate_lookup: HashSet<&str> = candidates.iter().copied().collect();
     let mut best: Option<GuessSuggestion> = None;
     let mut secret_only: Vec<GuessSuggestion> = Vec::new();
     let mut all_suggestions = if collect_all {
-        Some(Vec::with_capacity(allowed.len()))
+        Some(Vec::with_capacity(allowed_words().len()))
     } else {
         None
     };
 
-    let bar = ProgressBar::new(allowed.len() as u64);
+    let bar = ProgressBar::new(allowed_words().len() as u64);
     bar.set_message("Analyzing guesses");
     bar.set_style(
         ProgressStyle::default_bar()
@@ -270,40 +1113,35 @@ fn calculate_guess_suggestions(candidates: &[&str], collect_all: bool) -> GuessC
             )
             .expect("valid template"),
     );
+    let analyses = analyze_all_guesses(candidates, Some(&PATTERN_MATRIX), |done, _| {
+        bar.set_position(done as u64)
+    })
+    .expect("remaining secrets are valid words");
+    bar.finish_and_clear();
 
-    for guess in allowed {
-        if let Ok(entropy) = analyze_guess_against(guess, candidates.iter().copied()) {
-            let suggestion = GuessSuggestion {
-                word: entropy.guess().to_string(),
-                entropy_bits: entropy.entropy_bits(),
-                matching_secrets: entropy.total_secrets(),
-            };
-
-            if best.as_ref().map_or(true, |current| {
-                suggestion.entropy_bits > current.entropy_bits
-            }) {
-                best = Some(suggestion.clone());
-            }
+    for entropy in analyses {
+        let suggestion = GuessSuggestion::from_entropy(&entropy);
+        if best.as_ref().is_none_or(|current| {
+            compare_guess_scores(suggestion.score(), current.score(), &candidate_lookup).is_gt()
+        }) {
+            best = Some(suggestion.clone());
+        }
 
-            if candidate_lookup.contains(suggestion.word.as_str()) {
-                secret_only.push(suggestion.clone());
-            }
+        if candidate_lookup.contains(suggestion.word.as_str()) {
+            secret_only.push(suggestion.clone());
+        }
 
-            if let Some(all) = &mut all_suggestions {
-                all.push(suggestion);
-            }
+        if let Some(all) = &mut all_suggestions {
+            all.push(suggestion);
         }
-        bar.inc(1);
     }
 
-    bar.finish_and_clear();
-
-    secret_only.sort_by(|a, b| {
-        b.entropy_bits
-            .partial_cmp(&a.entropy_bits)
-            .unwrap_or(Ordering::Equal)
-    });
+    secret_only.sort_by(|a, b| compare_guess_scores(b.score(), a.score(), &candidate_lookup));
     secret_only.truncate(4);
+    // Later first turns are served from the cache in this order.
+    if let Some(all) = &mut all_suggestions {
+        all.sort_by(|a, b| compare_guess_scores(b.score(), a.score(), &candidate_lookup));
+    }
 
     GuessCalculation {
         insights: GuessInsights {
@@ -314,42 +1152,37 @@ fn calculate_guess_suggestions(candidates: &[&str], collect_all: bool) -> GuessC
     }
 }
 
-fn load_first_guess_cache(expected_total_secrets: usize) -> Option<Vec<FirstGuessCacheEntry>> {
-    let path = cache_file_path()?;
-    let data = fs::read(&path).ok()?;
+fn load_first_guess_cache(
+    path: &Path,
+    expected_total_secrets: usize,
+) -> Option<Vec<FirstGuessCacheEntry>> {
+    let data = fs::read(path).ok()?;
     let cache: FirstGuessCacheFile = serde_json::from_slice(&data).ok()?;
     if cache.version != FIRST_GUESS_CACHE_VERSION
         || cache.total_secrets != expected_total_secrets
         || cache.allowed_words != allowed_words().len()
+        || cache.word_lists_hash != word_lists_hash()
     {
         return None;
     }
     Some(cache.entries)
 }
 
+/// Saves `suggestions`, which must already be ranked best first.
 fn write_first_guess_cache(
+    path: &Path,
     suggestions: Vec<GuessSuggestion>,
     total_secrets: usize,
 ) -> io::Result<()> {
-    let path = match cache_file_path() {
-        Some(path) => path,
-        None => return Ok(()),
-    };
-
-    let mut entries: Vec<FirstGuessCacheEntry> = suggestions
+    let entries: Vec<FirstGuessCacheEntry> = suggestions
         .into_iter()
         .map(|suggestion| FirstGuessCacheEntry {
             guess: suggestion.word,
             entropy_bits: suggestion.entropy_bits,
+            reduction_fraction: suggestion.reduction_fraction,
         })
         .collect();
 
-    entries.sort_by(|a, b| {
-        b.entropy_bits
-            .partial_cmp(&a.entropy_bits)
-            .unwrap_or(Ordering::Equal)
-    });
-
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
@@ -358,6 +1191,7 @@ fn write_first_guess_cache(
         version: FIRST_GUESS_CACHE_VERSION,
         total_secrets,
         allowed_words: allowed_words().len(),
+        word_lists_hash: word_lists_hash(),
         entries,
     };
 
@@ -366,8 +1200,88 @@ fn write_first_guess_cache(
     Ok(())
 }
 
-fn cache_file_path() -> Option<PathBuf> {
-    cache_dir().map(|dir| dir.join("fibble").join(FIRST_GUESS_CACHE_FILE))
+/// Reads the statistics file, starting fresh when it is missing or unreadable.
+fn load_stats(dir: &Path) -> StatsFile {
+    fs::read(dir.join(STATS_FILE))
+        .ok()
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default()
+}
+
+fn write_stats(dir: &Path, stats: &StatsFile) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let file = File::create(dir.join(STATS_FILE))?;
+    serde_json::to_writer_pretty(file, stats)?;
+    Ok(())
+}
+
+/// Adds a finished game to the statistics file: `Some(attempts)` for a win, `None` for a loss,
+/// and `daily` for a daily puzzle. Failing to save only warns, since the game itself is
+/// already over.
+fn record_stats(
+    state_dir: Option<&Path>,
+    mode: GameMode,
+    won_in: Option<usize>,
+    daily: Option<u64>,
+) {
+    let Some(dir) = state_dir else {
+        return;
+    };
+    let mut stats = load_stats(dir);
+    let mode_stats = stats.for_mode(mode);
+    mode_stats.record(won_in);
+    if let Some(number) = daily {
+        mode_stats.record_daily(number);
+    }
+    if let Err(err) = write_stats(dir, &stats) {
+        eprintln!("Warning: could not save statistics: {err}");
+    }
+}
+
+fn reset_stats(dir: &Path) -> Result<(), Box<dyn Error>> {
+    match fs::remove_file(dir.join(STATS_FILE)) {
+        Ok(()) => println!("Statistics cleared."),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => println!("No statistics to clear."),
+        Err(err) => return Err(err.into()),
+    }
+    Ok(())
+}
+
+fn print_stats(stats: &StatsFile) {
+    let modes = [
+        ("Wordle", &stats.wordle),
+        ("Fibble", &stats.fibble),
+        ("Absurdle", &stats.absurdle),
+    ];
+    if modes.iter().all(|(_, tally)| tally.played == 0) {
+        println!("No games recorded yet.");
+        return;
+    }
+    for (name, tally) in modes {
+        if tally.played == 0 {
+            continue;
+        }
+        println!(
+            "{name}: {} played, {} won ({:.0}%), current streak {}, max streak {}",
+            tally.played,
+            tally.wins,
+            tally.wins as f64 * 100.0 / tally.played as f64,
+            tally.current_streak,
+            tally.max_streak
+        );
+        let widest = tally.distribution.iter().copied().max().unwrap_or(0).max(1);
+        for (idx, count) in tally.distribution.iter().enumerate() {
+            let bar = "#".repeat((count * 20).div_ceil(widest));
+            println!("  {:>2}: {bar} {count}", idx + 1);
+        }
+    }
+}
+
+/// Loads the opening book written by `fibble-bookgen`, ignoring stale or unreadable files.
+fn load_opening_book(dir: &Path) -> Option<OpeningBook> {
+    let data = fs::read(dir.join(OPENING_BOOK_FILE)).ok()?;
+    let book: OpeningBook = serde_json::from_slice(&data).ok()?;
+    book.is_current().then_some(book)
 }
 
 fn insights_from_cache(entries: &[FirstGuessCacheEntry], candidates: &[&str]) -> GuessInsights {
@@ -376,6 +1290,7 @@ fn insights_from_cache(entries: &[FirstGuessCacheEntry], candidates: &[&str]) ->
     let best_guess = entries.first().map(|entry| GuessSuggestion {
         word: entry.guess.clone(),
         entropy_bits: entry.entropy_bits,
+        reduction_fraction: entry.reduction_fraction,
         matching_secrets,
     });
 
@@ -385,6 +1300,7 @@ fn insights_from_cache(entries: &[FirstGuessCacheEntry], candidates: &[&str]) ->
             top_secret_guesses.push(GuessSuggestion {
                 word: entry.guess.clone(),
                 entropy_bits: entry.entropy_bits,
+                reduction_fraction: entry.reduction_fraction,
                 matching_secrets,
             });
             if top_secret_guesses.len() == 4 {
@@ -409,19 +1325,101 @@ struct GuessInsights {
 struct GuessSuggestion {
     word: String,
     entropy_bits: f64,
+    reduction_fraction: f64,
     matching_secrets: usize,
 }
 
+impl GuessSuggestion {
+    fn from_entropy(entropy: &GuessEntropy) -> Self {
+        Self {
+            word: entropy.guess().to_string(),
+            entropy_bits: entropy.entropy_bits(),
+            reduction_fraction: entropy.expected_reduction_fraction(),
+            matching_secrets: entropy.total_secrets(),
+        }
+    }
+
+    fn score(&self) -> (&str, f64) {
+        (&self.word, self.entropy_bits)
+    }
+}
+
 struct GuessCalculation {
     insights: GuessInsights,
     all_suggestions: Option<Vec<GuessSuggestion>>,
 }
 
+/// Win/loss tallies per mode, kept in [`STATS_FILE`] in the cache directory.
+#[derive(Default, Serialize, Deserialize)]
+struct StatsFile {
+    #[serde(default)]
+    wordle: ModeStats,
+    #[serde(default)]
+    fibble: ModeStats,
+    #[serde(default)]
+    absurdle: ModeStats,
+}
+
+impl StatsFile {
+    fn for_mode(&mut self, mode: GameMode) -> &mut ModeStats {
+        match mode {
+            GameMode::Wordle => &mut self.wordle,
+            GameMode::Fibble => &mut self.fibble,
+            GameMode::Absurdle => &mut self.absurdle,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct ModeStats {
+    played: usize,
+    wins: usize,
+    current_streak: usize,
+    max_streak: usize,
+    /// Wins by number of guesses; index `0` counts one-guess wins. Grows as needed, since
+    /// `--max-attempts` can allow more guesses than the mode's default.
+    distribution: Vec<usize>,
+    /// The latest daily puzzle finished in this mode. Puzzle numbers count UTC days, so this
+    /// doubles as the date.
+    #[serde(default)]
+    last_daily: Option<u64>,
+}
+
+impl ModeStats {
+    fn record(&mut self, won_in: Option<usize>) {
+        self.played += 1;
+        match won_in {
+            Some(attempts) => {
+                self.wins += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+                if self.distribution.len() < attempts {
+                    self.distribution.resize(attempts, 0);
+                }
+                self.distribution[attempts - 1] += 1;
+            }
+            None => self.current_streak = 0,
+        }
+    }
+
+    /// Notes that daily puzzle `number` was finished. The record never moves backwards, so a
+    /// game started before midnight and finished after it cannot undo the later day's.
+    fn record_daily(&mut self, number: u64) {
+        self.last_daily = self.last_daily.max(Some(number));
+    }
+
+    /// Whether daily puzzle `number` (or a later one) has already been finished.
+    fn completed_daily(&self, number: u64) -> bool {
+        self.last_daily.is_some_and(|last| last >= number)
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct FirstGuessCacheFile {
     version: u32,
     total_secrets: usize,
     allowed_words: usize,
+    word_lists_hash: u64,
     entries: Vec<FirstGuessCacheEntry>,
 }
 
@@ -429,4 +1427,212 @@ struct FirstGuessCacheFile {
 struct FirstGuessCacheEntry {
     guess: String,
     entropy_bits: f64,
+    reduction_fraction: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daily_puzzles_are_finished_once_per_day() {
+        let mut stats = ModeStats::default();
+        assert!(!stats.completed_daily(100));
+
+        stats.record_daily(100);
+        assert!(stats.completed_daily(100));
+        assert!(stats.completed_daily(99));
+        assert!(!stats.completed_daily(101));
+
+        // Finishing yesterday's puzzle after midnight leaves today's record in place.
+        stats.record_daily(101);
+        stats.record_daily(100);
+        assert_eq!(stats.last_daily, Some(101));
+        assert!(!stats.completed_daily(102));
+    }
+
+    #[test]
+    fn replay_files_parse_with_line_numbers() {
+        let replay = parse_replay(
+            "# a Fibble game\n\
+             secret cigar\n\
+             MODE fibble\n\
+             seed 42\n\
+             \n\
+             crane\n\
+             cigar\n",
+        )
+        .unwrap();
+        assert_eq!(replay.secret, "cigar");
+        assert_eq!(replay.mode, GameMode::Fibble);
+        assert_eq!(replay.seed, 42);
+        assert_eq!(
+            replay.guesses,
+            vec![(6, "crane".to_string()), (7, "cigar".to_string())]
+        );
+
+        let error = |text: &str| parse_replay(text).err().unwrap();
+        assert_eq!(error("crane\n"), "replay file has no secret line");
+        assert_eq!(
+            error("secret cigar\ncrane slate\n"),
+            "line 2: expected a guess or a secret, mode, or seed line"
+        );
+        assert_eq!(
+            error("secret cigar extra\n"),
+            "line 1: unexpected \"extra\""
+        );
+        assert_eq!(
+            error("secret cigar\n\nseed\n"),
+            "line 3: missing value for seed"
+        );
+        assert_eq!(
+            error("secret cigar\nseed soon\n"),
+            "line 2: invalid seed soon; expected a whole number"
+        );
+        assert!(error("mode chess\nsecret cigar\n").starts_with("line 1: "));
+    }
+
+    #[test]
+    fn coach_grades_guesses_against_the_best() {
+        assert_eq!(coach_verdict(5.98, 6.0), "a great guess");
+        assert_eq!(coach_verdict(6.2, 6.0), "a great guess");
+        assert_eq!(coach_verdict(4.5, 6.0), "a solid guess");
+        assert_eq!(coach_verdict(4.49, 6.0), "a weak guess");
+
+        // With at most one candidate left there is nothing to coach.
+        let game = Wordle::new("cigar").unwrap();
+        let mut insights = GuessInsights {
+            best_guess: Some(GuessSuggestion {
+                word: "CIGAR".into(),
+                entropy_bits: 0.0,
+                reduction_fraction: 0.0,
+                matching_secrets: 1,
+            }),
+            top_secret_guesses: Vec::new(),
+        };
+        assert_eq!(coach_line(&game, "CRANE", &insights), None);
+
+        let best = insights.best_guess.as_mut().unwrap();
+        best.matching_secrets = 2;
+        best.entropy_bits = 20.0;
+        let line = coach_line(&game, "CRANE", &insights).unwrap();
+        assert!(line.starts_with("Coach: that was a weak guess ("), "{line}");
+    }
+
+    #[test]
+    fn suggested_guess_breaks_ties_like_the_library() {
+        // Hundreds of guesses split two candidates evenly; the possible secret should win.
+        let insights = calculate_guess_suggestions(&["REBUT", "CIGAR"], false).insights;
+        assert_eq!(insights.best_guess.unwrap().word, "CIGAR");
+        let top: Vec<String> = insights
+            .top_secret_guesses
+            .into_iter()
+            .map(|suggestion| suggestion.word)
+            .collect();
+        assert_eq!(top, ["CIGAR", "REBUT"]);
+    }
+
+    fn why(game: &Wordle, word: &str) -> String {
+        let elimination = explain_elimination(game, word).unwrap().unwrap();
+        elimination_message(game, word, &elimination)
+    }
+
+    #[test]
+    fn elimination_messages_respect_duplicate_letters() {
+        let mut game = Wordle::new("valor").unwrap();
+        game.submit_guess("llama").unwrap();
+        // ALOFT has an L, but LLAMA's green L would claim it, leaving the first L gray.
+        assert_eq!(
+            why(&game, "ALOFT"),
+            "Against ALOFT, guess 1 (LLAMA) would show the L in position 1 as absent, not \
+             present."
+        );
+        assert_eq!(
+            why(&game, "MOIST"),
+            "MOIST does not contain L, which guess 1 (LLAMA) marked present."
+        );
+
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("crane").unwrap();
+        assert_eq!(
+            why(&game, "SUGAR"),
+            "SUGAR does not have C in position 1, which guess 1 (CRANE) marked correct."
+        );
+        assert_eq!(
+            why(&game, "CHARM"),
+            "CHARM has A in position 3, but guess 1 (CRANE) marked it present elsewhere."
+        );
+        assert_eq!(
+            why(&game, "CEDAR"),
+            "CEDAR contains E, which guess 1 (CRANE) marked absent."
+        );
+    }
+
+    #[test]
+    fn games_without_hints_still_record_statistics() {
+        let dir = env::temp_dir().join(format!("fibble-no-hints-{}", std::process::id()));
+        let config = Config {
+            mode: GameMode::Wordle,
+            secret: None,
+            seed: None,
+            daily: false,
+            color: false,
+            assist: false,
+            opener: None,
+            no_opener: false,
+            max_attempts: None,
+            replay: None,
+            coach: true,
+            hints: false,
+            show_stats: false,
+            reset_stats: false,
+            allow_unknown: false,
+            practice: false,
+            cache_dir: Some(dir.clone()),
+            state_dir: Some(dir.clone()),
+        };
+
+        let mut game = Wordle::new("cigar").unwrap();
+        // Nothing is ranked, so the progress bar never starts.
+        assert!(turn_insights(&game, &config, None).is_none());
+        game.submit_guess("crane").unwrap();
+        assert!(turn_insights(&game, &config, None).is_none());
+        game.submit_guess("cigar").unwrap();
+        announce_result(&game, &config, None);
+
+        let mut stats = load_stats(&dir);
+        let _ = fs::remove_dir_all(&dir);
+        let wordle = stats.for_mode(GameMode::Wordle);
+        assert_eq!((wordle.played, wordle.wins), (1, 1));
+    }
+
+    #[test]
+    fn stale_first_guess_caches_are_rebuilt() {
+        let dir = env::temp_dir().join(format!("fibble-stale-cache-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(FIRST_GUESS_CACHE_FILE);
+        let candidates = ["CIGAR", "REBUT"];
+
+        // Everything matches except the fingerprint, as after editing a word list in place.
+        let stale = FirstGuessCacheFile {
+            version: FIRST_GUESS_CACHE_VERSION,
+            total_secrets: candidates.len(),
+            allowed_words: allowed_words().len(),
+            word_lists_hash: word_lists_hash().wrapping_add(1),
+            entries: vec![FirstGuessCacheEntry {
+                guess: "XYLYL".into(),
+                entropy_bits: 99.0,
+                reduction_fraction: 1.0,
+            }],
+        };
+        serde_json::to_writer(File::create(&path).unwrap(), &stale).unwrap();
+        assert!(load_first_guess_cache(&path, candidates.len()).is_none());
+
+        let insights = suggestions_for(&candidates, true, Some(&dir));
+        let rebuilt = load_first_guess_cache(&path, candidates.len());
+        let _ = fs::remove_dir_all(&dir);
+        assert_ne!(insights.best_guess.unwrap().word, "XYLYL");
+        let rebuilt = rebuilt.expect("the cache is rewritten with the current fingerprint");
+        assert_ne!(rebuilt[0].guess, "XYLYL");
+    }
 }
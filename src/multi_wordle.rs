@@ -0,0 +1,315 @@
+use crate::{
+    allowed_words, analyze_guess_against, compare_guess_scores, normalize_length,
+    remaining_secrets, score, GameMode, GuessResult, Wordle, WordleError, WORD_LENGTH,
+};
+use rand::{thread_rng, Rng};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// How many boards a Quordle game has.
+pub const QUORDLE_BOARDS: usize = 4;
+
+/// Several boards played at once with shared guesses, like Dordle (two boards) or Quordle
+/// (four).
+///
+/// Every board has its own secret and the same mode. A guess is scored on every board; a
+/// board stops recording rows once it is solved, and the game ends when every board is solved
+/// or the shared attempts run out. The attempt limit is the mode's single-board limit plus one
+/// per extra board, which gives Dordle's 7 and Quordle's 9.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiWordle {
+    boards: Vec<Wordle>,
+    max_attempts: usize,
+    attempts: usize,
+}
+
+impl MultiWordle {
+    /// Creates one board per secret, all in `mode`.
+    ///
+    /// Errors with [`WordleError::NoBoards`] when `secrets` is empty, or with the first
+    /// secret's validation error.
+    pub fn new(secrets: &[&str], mode: GameMode) -> Result<Self, WordleError> {
+        if secrets.is_empty() {
+            return Err(WordleError::NoBoards);
+        }
+        let max_attempts = mode.max_attempts() + secrets.len() - 1;
+        let boards = secrets
+            .iter()
+            .map(|secret| {
+                let mut board = Wordle::new_with_mode(secret, mode)?;
+                board.max_attempts = max_attempts;
+                Ok(board)
+            })
+            .collect::<Result<Vec<_>, WordleError>>()?;
+        Ok(Self {
+            boards,
+            max_attempts,
+            attempts: 0,
+        })
+    }
+
+    /// Creates a Quordle game: [`QUORDLE_BOARDS`] boards sharing the mode's attempts plus three,
+    /// which is nine in Wordle mode.
+    pub fn quordle(secrets: &[&str; QUORDLE_BOARDS], mode: GameMode) -> Result<Self, WordleError> {
+        Self::new(secrets, mode)
+    }
+
+    /// Scores `guess` on every board, using the thread-local RNG for Fibble lies.
+    pub fn submit_guess(&mut self, guess: &str) -> Result<Vec<GuessResult>, WordleError> {
+        self.submit_guess_with_rng(guess, &mut thread_rng())
+    }
+
+    /// Scores `guess` on every board and returns one row per board, in board order.
+    ///
+    /// Boards that were already solved still get a (truthful) row so the result lines up with
+    /// [`MultiWordle::boards`], but it is not added to their history. The guess is validated
+    /// once, before any board changes.
+    pub fn submit_guess_with_rng(
+        &mut self,
+        guess: &str,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<GuessResult>, WordleError> {
+        if self.is_solved() {
+            return Err(WordleError::AlreadySolved);
+        }
+        if self.attempts >= self.max_attempts {
+            return Err(WordleError::OutOfAttempts {
+                max: self.max_attempts,
+            });
+        }
+        let normalized = normalize_length(guess, WORD_LENGTH)?;
+        self.boards[0].ensure_allowed(&normalized)?;
+
+        let rows = self
+            .boards
+            .iter_mut()
+            .map(|board| {
+                if board.is_solved() {
+                    return Ok(GuessResult {
+                        letters: score(board.secret(), &normalized),
+                        guess: normalized.clone(),
+                        lie_indices: Vec::new(),
+                    });
+                }
+                board.submit_guess_with_rng(&normalized, rng).cloned()
+            })
+            .collect::<Result<Vec<_>, WordleError>>()?;
+        self.attempts += 1;
+        Ok(rows)
+    }
+
+    /// Returns the boards in the order their secrets were given.
+    pub fn boards(&self) -> &[Wordle] {
+        &self.boards
+    }
+
+    /// Returns how many guesses have been played.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// Returns how many guesses the game allows in total.
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Returns how many more guesses may be submitted; `0` once the game is over.
+    pub fn remaining_attempts(&self) -> usize {
+        if self.is_over() {
+            0
+        } else {
+            self.max_attempts - self.attempts
+        }
+    }
+
+    /// Returns how many boards have been solved.
+    pub fn solved_boards(&self) -> usize {
+        self.boards.iter().filter(|board| board.is_solved()).count()
+    }
+
+    /// Whether every board has been solved.
+    pub fn is_solved(&self) -> bool {
+        self.boards.iter().all(Wordle::is_solved)
+    }
+
+    /// Whether the game has ended, either fully solved or out of attempts.
+    pub fn is_over(&self) -> bool {
+        self.is_solved() || self.attempts >= self.max_attempts
+    }
+
+    /// Returns the secrets still consistent with each board's history, in board order.
+    ///
+    /// Solved boards report just their secret.
+    pub fn remaining_secrets(&self) -> Vec<Vec<&'static str>> {
+        self.boards.iter().map(remaining_secrets).collect()
+    }
+
+    /// Like [`MultiWordle::remaining_secrets`], but skips solved boards, pairing each unsolved
+    /// board's index with its candidates.
+    pub fn unsolved_remaining_secrets(&self) -> Vec<(usize, Vec<&'static str>)> {
+        self.boards
+            .iter()
+            .enumerate()
+            .filter(|(_, board)| !board.is_solved())
+            .map(|(index, board)| (index, remaining_secrets(board)))
+            .collect()
+    }
+
+    /// Suggests the allowed guess that reveals the most information across every unsolved
+    /// board, and how many bits that is.
+    ///
+    /// The boards' secrets are independent, so a guess's combined information is the sum of
+    /// its entropy on each board. Ties go to a word that could still be the secret on some
+    /// board, then alphabetically. Returns `None` once the game is over.
+    pub fn best_combined_guess(&self) -> Option<(String, f64)> {
+        if self.is_over() {
+            return None;
+        }
+        let boards: Vec<Vec<&str>> = self
+            .unsolved_remaining_secrets()
+            .into_iter()
+            .map(|(_, candidates)| candidates)
+            .filter(|candidates| !candidates.is_empty())
+            .collect();
+        if boards.is_empty() {
+            return None;
+        }
+        let live: HashSet<&str> = boards.iter().flatten().copied().collect();
+        best_combined_among(&boards, &live)
+    }
+}
+
+fn combined_bits(guess: &str, boards: &[Vec<&str>]) -> f64 {
+    boards
+        .iter()
+        .filter_map(|candidates| analyze_guess_against(guess, candidates.iter().copied()).ok())
+        .map(|entropy| entropy.entropy_bits())
+        .sum()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn best_combined_among(boards: &[Vec<&str>], live: &HashSet<&str>) -> Option<(String, f64)> {
+    allowed_words()
+        .iter()
+        .map(|guess| (guess, combined_bits(guess, boards)))
+        .max_by(|a, b| compare_guess_scores((a.0, a.1), (b.0, b.1), live))
+        .map(|(guess, bits)| (guess.clone(), bits))
+}
+
+#[cfg(feature = "rayon")]
+fn best_combined_among(boards: &[Vec<&str>], live: &HashSet<&str>) -> Option<(String, f64)> {
+    allowed_words()
+        .par_iter()
+        .map(|guess| (guess, combined_bits(guess, boards)))
+        .max_by(|a, b| compare_guess_scores((a.0, a.1), (b.0, b.1), live))
+        .map(|(guess, bits)| (guess.clone(), bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solving_one_board_leaves_the_other_in_play() {
+        let mut game = MultiWordle::new(&["cigar", "rebut"], GameMode::Wordle).unwrap();
+        assert_eq!(game.max_attempts(), 7);
+
+        let rows = game.submit_guess("cigar").unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].is_correct());
+        assert!(!rows[1].is_correct());
+        assert!(game.boards()[0].is_solved());
+        assert!(!game.boards()[1].is_solved());
+        assert_eq!(game.solved_boards(), 1);
+        assert!(!game.is_over());
+        assert_eq!(game.remaining_attempts(), 6);
+
+        let rows = game.submit_guess("rebut").unwrap();
+        assert!(rows[1].is_correct());
+        // The solved board does not record the extra row.
+        assert_eq!(game.boards()[0].guesses().len(), 1);
+        assert_eq!(game.boards()[1].guesses().len(), 2);
+        assert!(game.is_solved());
+        assert_eq!(game.submit_guess("crane"), Err(WordleError::AlreadySolved));
+    }
+
+    #[test]
+    fn invalid_guesses_leave_every_board_untouched() {
+        let mut game = MultiWordle::new(&["cigar", "rebut"], GameMode::Wordle).unwrap();
+        assert!(matches!(
+            game.submit_guess("zzzzz"),
+            Err(WordleError::UnknownWord { .. })
+        ));
+        assert_eq!(game.attempts(), 0);
+        assert!(game.boards().iter().all(|board| board.guesses().is_empty()));
+    }
+
+    #[test]
+    fn remaining_secrets_are_reported_per_board() {
+        let mut game = MultiWordle::new(&["cigar", "rebut"], GameMode::Wordle).unwrap();
+        game.submit_guess("cairn").unwrap();
+        let remaining = game.remaining_secrets();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining[0].contains(&"CIGAR"));
+        assert!(!remaining[0].contains(&"REBUT"));
+        assert!(remaining[1].contains(&"REBUT"));
+        assert!(!remaining[1].contains(&"CIGAR"));
+    }
+
+    #[test]
+    fn quordle_keeps_going_after_one_board_is_solved() {
+        let mut game =
+            MultiWordle::quordle(&["cigar", "rebut", "sissy", "humph"], GameMode::Wordle).unwrap();
+        assert_eq!(game.max_attempts(), 9);
+
+        game.submit_guess("crane").unwrap();
+        let rows = game.submit_guess("cigar").unwrap();
+        assert_eq!(rows.len(), QUORDLE_BOARDS);
+        assert!(rows[0].is_correct());
+        assert_eq!(game.solved_boards(), 1);
+        assert!(!game.is_over());
+        assert_eq!(game.remaining_attempts(), 7);
+
+        let unsolved = game.unsolved_remaining_secrets();
+        assert_eq!(
+            unsolved.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert!(unsolved[0].1.contains(&"REBUT"));
+        assert!(unsolved[2].1.contains(&"HUMPH"));
+    }
+
+    #[test]
+    fn combined_suggestion_beats_each_boards_own_best() {
+        let mut game =
+            MultiWordle::quordle(&["cigar", "rebut", "sissy", "humph"], GameMode::Wordle).unwrap();
+        game.submit_guess("crane").unwrap();
+        game.submit_guess("moist").unwrap();
+
+        let (guess, bits) = game.best_combined_guess().unwrap();
+        let boards: Vec<Vec<&str>> = game
+            .unsolved_remaining_secrets()
+            .into_iter()
+            .map(|(_, candidates)| candidates)
+            .collect();
+        assert!((combined_bits(&guess, &boards) - bits).abs() < 1e-9);
+        for candidates in &boards {
+            let own_best = crate::information_guess_among(candidates).unwrap();
+            assert!(combined_bits(own_best.guess(), &boards) <= bits + 1e-9);
+        }
+
+        for secret in ["cigar", "rebut", "sissy", "humph"] {
+            game.submit_guess(secret).unwrap();
+        }
+        assert_eq!(game.best_combined_guess(), None);
+    }
+
+    #[test]
+    fn rejects_an_empty_board_list() {
+        assert_eq!(
+            MultiWordle::new(&[], GameMode::Wordle),
+            Err(WordleError::NoBoards)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    encode_pattern, ensure_allowed, information_guess_among, normalize, pattern_code_to_string,
+    secret_words, word_lists_hash, GuessResult, Word, WordleError, WORD_LENGTH,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::path::PathBuf;
+
+/// Bumped whenever [`OpeningBook`]'s layout or generation rules change.
+pub const OPENING_BOOK_VERSION: u32 = 2;
+
+/// The file name `fibble-bookgen` writes and the CLI reads inside the cache directory.
+pub const OPENING_BOOK_FILE: &str = "opening_book.json";
+
+/// The environment variable that overrides the platform cache directory.
+pub const CACHE_DIR_ENV: &str = "FIBBLE_CACHE_DIR";
+
+/// Resolves the cache directory the binaries share: `dir_override` (their `--cache-dir`) wins,
+/// then [`CACHE_DIR_ENV`], then `fibble` inside the platform cache directory.
+pub fn cache_dir_path(dir_override: Option<PathBuf>) -> Option<PathBuf> {
+    dir_override
+        .or_else(|| env::var_os(CACHE_DIR_ENV).map(PathBuf::from))
+        .or_else(|| dirs::cache_dir().map(|dir| dir.join("fibble")))
+}
+
+/// The best second guess for every pattern an opener can show, precomputed for plain Wordle.
+///
+/// Generating a book runs one allowed-word scan per pattern, each against only the secrets
+/// that pattern leaves, so the total work is about one full first-guess scan. Books record
+/// [`OPENING_BOOK_VERSION`] and [`word_lists_hash`]; check [`OpeningBook::is_current`] before
+/// trusting a saved one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpeningBook {
+    version: u32,
+    word_lists_hash: u64,
+    opener: String,
+    follow_ups: BTreeMap<String, String>,
+}
+
+impl OpeningBook {
+    /// Builds the book for `opener` against the bundled secrets.
+    pub fn generate(opener: &str) -> Result<Self, WordleError> {
+        let opener = normalize(opener)?;
+        ensure_allowed(&opener)?;
+        let secrets: Vec<&str> = secret_words().iter().map(|word| word.as_str()).collect();
+        Ok(Self::generate_among(opener, &secrets))
+    }
+
+    fn generate_among(opener: String, secrets: &[&str]) -> Self {
+        let opener_word = Word::known(&opener);
+        let mut buckets: HashMap<usize, Vec<&str>> = HashMap::new();
+        for secret in secrets {
+            let digits = opener_word.pattern_against(&Word::known(secret));
+            buckets
+                .entry(encode_pattern(&digits))
+                .or_default()
+                .push(secret);
+        }
+
+        let follow_ups = buckets
+            .into_iter()
+            .filter_map(|(code, bucket)| {
+                // With two or fewer left, guessing a candidate can win outright.
+                let follow_up = if bucket.len() <= 2 {
+                    bucket[0].to_string()
+                } else {
+                    information_guess_among(&bucket)?.guess().to_string()
+                };
+                Some((pattern_code_to_string(code, WORD_LENGTH), follow_up))
+            })
+            .collect();
+
+        Self {
+            version: OPENING_BOOK_VERSION,
+            word_lists_hash: word_lists_hash(),
+            opener,
+            follow_ups,
+        }
+    }
+
+    /// Returns the opener the book was generated for.
+    pub fn opener(&self) -> &str {
+        &self.opener
+    }
+
+    /// Whether the book matches this build's format and bundled word lists.
+    pub fn is_current(&self) -> bool {
+        self.version == OPENING_BOOK_VERSION && self.word_lists_hash == word_lists_hash()
+    }
+
+    /// Returns the stored second guess after `row`, if `row` played the book's opener.
+    pub fn follow_up(&self, row: &GuessResult) -> Option<&str> {
+        if row.guess() != self.opener {
+            return None;
+        }
+        self.follow_up_for_pattern(&row.pattern_string())
+    }
+
+    /// Returns the stored second guess after the opener showed `pattern`, such as `GYBBB`.
+    pub fn follow_up_for_pattern(&self, pattern: &str) -> Option<&str> {
+        self.follow_ups.get(pattern).map(String::as_str)
+    }
+
+    /// Returns how many patterns have a stored follow-up.
+    pub fn len(&self) -> usize {
+        self.follow_ups.len()
+    }
+
+    /// Whether the book stores no follow-ups.
+    pub fn is_empty(&self) -> bool {
+        self.follow_ups.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wordle;
+
+    #[test]
+    fn follow_ups_split_each_opener_bucket() {
+        let secrets = [
+            "CIGAR", "CHAIR", "CRANE", "REBUT", "SISSY", "HUMPH", "AWAKE",
+        ];
+        let book = OpeningBook::generate_among(String::from("CAIRN"), &secrets);
+        assert!(book.is_current());
+        assert_eq!(book.opener(), "CAIRN");
+
+        let mut game = Wordle::new("cigar").unwrap();
+        let row = game.submit_guess("cairn").unwrap();
+        // CIGAR and CHAIR both show GYYYB, so the follow-up is one of them.
+        let follow_up = book.follow_up(row).unwrap();
+        assert!(["CIGAR", "CHAIR"].contains(&follow_up));
+
+        assert!(book.len() <= secrets.len());
+        assert_eq!(book.follow_up_for_pattern("GGGGG"), None);
+    }
+
+    #[test]
+    fn follow_up_ignores_other_openers() {
+        let book = OpeningBook::generate_among(String::from("CAIRN"), &["CIGAR", "REBUT"]);
+        let mut game = Wordle::new("cigar").unwrap();
+        let row = game.submit_guess("crane").unwrap();
+        assert_eq!(book.follow_up(row), None);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    allowed_words, analyze_guess_against, encode_pattern, normalize, secret_words, GuessEntropy,
+    Word, WordleError, PATTERN_SPACE,
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// How many guesses [`analyze_all_guesses`] scores between progress reports.
+const PROGRESS_BATCH: usize = 256;
+
+/// Every guess-versus-secret pattern code for the bundled lists, computed once up front.
+///
+/// Building scores each allowed guess against each secret, so it costs the same as one full
+/// [`crate::best_information_guess`] scan (a second or so in release builds) and holds one
+/// byte per pair: 12,970 allowed words by 3,241 secrets, about 42 MB (40 MiB), for the bundled
+/// lists. Every later lookup is a single index, which pays off for solvers that rescan the
+/// allowed words on every turn.
+#[derive(Debug, Clone)]
+pub struct PatternMatrix {
+    guesses: &'static [String],
+    secrets: &'static [String],
+    guess_lookup: HashMap<&'static str, usize>,
+    secret_lookup: HashMap<&'static str, usize>,
+    codes: Vec<u8>,
+}
+
+// Pattern codes are stored as bytes, which only works while they fit in one.
+const _: () = assert!(PATTERN_SPACE <= u8::MAX as usize + 1);
+
+impl PatternMatrix {
+    /// Scores every allowed word against every secret word.
+    pub fn build() -> Self {
+        Self::from_lists(allowed_words(), secret_words())
+    }
+
+    fn from_lists(guesses: &'static [String], secrets: &'static [String]) -> Self {
+        let secret_values: Vec<Word> = secrets.iter().map(|word| Word::known(word)).collect();
+        let mut codes = Vec::with_capacity(guesses.len() * secrets.len());
+        for guess in guesses {
+            let guess = Word::known(guess);
+            for secret in &secret_values {
+                codes.push(encode_pattern(&guess.pattern_against(secret)) as u8);
+            }
+        }
+        Self {
+            guesses,
+            secrets,
+            guess_lookup: index_words(guesses),
+            secret_lookup: index_words(secrets),
+            codes,
+        }
+    }
+
+    /// Returns the pattern code `guesses()[guess_idx]` shows against `secrets()[secret_idx]`.
+    pub fn pattern(&self, guess_idx: usize, secret_idx: usize) -> u8 {
+        assert!(secret_idx < self.secrets.len(), "secret index out of range");
+        self.codes[guess_idx * self.secrets.len() + secret_idx]
+    }
+
+    /// Returns the guess words indexing the rows, in allowed-list order.
+    pub fn guesses(&self) -> &'static [String] {
+        self.guesses
+    }
+
+    /// Returns the secret words indexing the columns, in secret-list order.
+    pub fn secrets(&self) -> &'static [String] {
+        self.secrets
+    }
+
+    /// Returns the row of `guess` (already uppercase), if it is an allowed word.
+    pub fn guess_index(&self, guess: &str) -> Option<usize> {
+        self.guess_lookup.get(guess).copied()
+    }
+
+    /// Returns the column of `secret` (already uppercase), if it is a bundled secret.
+    pub fn secret_index(&self, secret: &str) -> Option<usize> {
+        self.secret_lookup.get(secret).copied()
+    }
+}
+
+fn index_words(words: &'static [String]) -> HashMap<&'static str, usize> {
+    words
+        .iter()
+        .enumerate()
+        .map(|(idx, word)| (word.as_str(), idx))
+        .collect()
+}
+
+/// Like [`crate::analyze_guess_against`], but reads patterns from `matrix` instead of
+/// scoring each pair.
+pub fn analyze_with_matrix(
+    matrix: &PatternMatrix,
+    guess_idx: usize,
+    secret_indices: impl IntoIterator<Item = usize>,
+) -> GuessEntropy {
+    let mut pattern_counts = vec![0usize; PATTERN_SPACE];
+    for secret_idx in secret_indices {
+        pattern_counts[matrix.pattern(guess_idx, secret_idx) as usize] += 1;
+    }
+
+    GuessEntropy {
+        guess: matrix.guesses[guess_idx].clone(),
+        pattern_counts,
+    }
+}
+
+/// Scores every allowed guess against `candidates` and collects the analyses in allowed-list
+/// order; see [`for_each_guess_analysis`] for the arguments.
+pub fn analyze_all_guesses(
+    candidates: &[&str],
+    matrix: Option<&PatternMatrix>,
+    progress: impl FnMut(usize, usize),
+) -> Result<Vec<GuessEntropy>, WordleError> {
+    let mut analyses = Vec::with_capacity(allowed_words().len());
+    for_each_guess_analysis(candidates, matrix, progress, |analysis| {
+        analyses.push(analysis)
+    })?;
+    Ok(analyses)
+}
+
+/// Scores every allowed guess against `candidates`, handing each analysis to `visit` in
+/// allowed-list order and reporting `(done, total)` guesses to `progress` as the scan
+/// advances. Only one batch of analyses is alive at a time, so `visit` can reduce them to
+/// whatever it keeps.
+///
+/// Pass a `matrix` to read patterns from it whenever it has a column for every candidate and
+/// a row for the guess; anything else is scored pair by pair. The caller owns the matrix, so
+/// it decides whether the memory is worth keeping between calls. Candidates are
+/// case-insensitive; the first that is not a `WORD_LENGTH`-letter word is returned as an
+/// error. With the `rayon` feature each batch of guesses is scored in parallel, but
+/// `progress` and `visit` are only ever called from the calling thread.
+pub fn for_each_guess_analysis(
+    candidates: &[&str],
+    matrix: Option<&PatternMatrix>,
+    mut progress: impl FnMut(usize, usize),
+    mut visit: impl FnMut(GuessEntropy),
+) -> Result<(), WordleError> {
+    let candidates = candidates
+        .iter()
+        .map(|word| normalize(word))
+        .collect::<Result<Vec<String>, WordleError>>()?;
+    let columns = matrix.and_then(|matrix| {
+        candidates
+            .iter()
+            .map(|word| matrix.secret_index(word))
+            .collect::<Option<Vec<usize>>>()
+            .map(|columns| (matrix, columns))
+    });
+
+    let guesses = allowed_words();
+    let total = guesses.len();
+    progress(0, total);
+    for start in (0..total).step_by(PROGRESS_BATCH) {
+        let batch = start..(start + PROGRESS_BATCH).min(total);
+        let end = batch.end;
+        analyze_batch(batch, |guess_idx| {
+            let guess = &guesses[guess_idx];
+            if let Some((matrix, columns)) = &columns
+                && let Some(row) = matrix.guess_index(guess)
+            {
+                return Ok(analyze_with_matrix(matrix, row, columns.iter().copied()));
+            }
+            analyze_guess_against(guess, candidates.iter().map(String::as_str))
+        })?
+        .into_iter()
+        .for_each(&mut visit);
+        progress(end, total);
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "rayon"))]
+fn analyze_batch(
+    batch: std::ops::Range<usize>,
+    analyze: impl Fn(usize) -> Result<GuessEntropy, WordleError>,
+) -> Result<Vec<GuessEntropy>, WordleError> {
+    batch.map(analyze).collect()
+}
+
+#[cfg(feature = "rayon")]
+fn analyze_batch(
+    batch: std::ops::Range<usize>,
+    analyze: impl Fn(usize) -> Result<GuessEntropy, WordleError> + Sync + Send,
+) -> Result<Vec<GuessEntropy>, WordleError> {
+    batch.into_par_iter().map(analyze).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_agrees_with_direct_analysis() {
+        let matrix = PatternMatrix::from_lists(&allowed_words()[..40], &secret_words()[..60]);
+        let secrets: Vec<&str> = matrix.secrets().iter().map(|word| word.as_str()).collect();
+        for (guess_idx, guess) in matrix.guesses().iter().enumerate() {
+            let direct = analyze_guess_against(guess, secrets.iter().copied()).unwrap();
+            let cached = analyze_with_matrix(&matrix, guess_idx, 0..secrets.len());
+            assert_eq!(cached.guess(), direct.guess());
+            assert_eq!(cached.pattern_counts(), direct.pattern_counts());
+        }
+    }
+
+    #[test]
+    fn analyze_all_guesses_reports_progress_to_the_end() {
+        // CIGAR plus a non-secret word scores pair by pair, without building the full matrix.
+        let candidates = ["CIGAR", "CAIRN"];
+        let mut reports = Vec::new();
+        let analyses =
+            analyze_all_guesses(&candidates, None, |done, total| reports.push((done, total)))
+                .unwrap();
+
+        let total = allowed_words().len();
+        assert_eq!(analyses.len(), total);
+        assert_eq!(reports.first(), Some(&(0, total)));
+        assert_eq!(reports.last(), Some(&(total, total)));
+        assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        let direct = analyze_guess_against("CAIRN", candidates).unwrap();
+        let idx = allowed_words()
+            .iter()
+            .position(|word| word == "CAIRN")
+            .unwrap();
+        assert_eq!(analyses[idx].pattern_counts(), direct.pattern_counts());
+    }
+
+    #[test]
+    fn analyze_all_guesses_reads_a_caller_matrix_where_it_can() {
+        // The small matrix covers only some guesses, so the rest are scored directly.
+        let matrix = PatternMatrix::from_lists(&allowed_words()[..40], &secret_words()[..60]);
+        let candidates: Vec<String> = secret_words()[..60].iter().step_by(3).cloned().collect();
+        let candidates: Vec<&str> = candidates.iter().map(String::as_str).collect();
+        let with_matrix = analyze_all_guesses(&candidates, Some(&matrix), |_, _| {}).unwrap();
+        let without = analyze_all_guesses(&candidates, None, |_, _| {}).unwrap();
+        assert_eq!(with_matrix.len(), without.len());
+        for (cached, direct) in with_matrix.iter().zip(&without) {
+            assert_eq!(cached.guess(), direct.guess());
+            assert_eq!(cached.pattern_counts(), direct.pattern_counts());
+        }
+
+        assert_eq!(
+            analyze_all_guesses(&["cigar", "ci-ar"], None, |_, _| {}).unwrap_err(),
+            WordleError::NonAlphabetic {
+                word: "ci-ar".into()
+            }
+        );
+    }
+
+    #[test]
+    fn indexes_find_rows_and_columns() {
+        let matrix = PatternMatrix::from_lists(&allowed_words()[..3], &secret_words()[..5]);
+        let third = &secret_words()[2];
+        assert_eq!(matrix.secret_index(third), Some(2));
+        assert_eq!(matrix.secret_index("ZZZZZ"), None);
+        assert_eq!(matrix.guess_index(&allowed_words()[1]), Some(1));
+        assert_eq!(matrix.guess_index("ZZZZZ"), None);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::word::allowed_word_list;
+use crate::{
+    analyze_guess_against, encode_pattern, ranked_information_guesses, remaining_secrets, GameMode,
+    GuessEntropy, Word, Wordle, PATTERN_SPACE,
+};
+use std::collections::{HashMap, HashSet};
+
+/// The largest candidate set [`optimal_remaining_guesses`] will search exhaustively.
+pub const OPTIMAL_SEARCH_LIMIT: usize = 20;
+
+/// How many of the highest-entropy first guesses [`best_two_ply_guess`] looks ahead from.
+pub const TWO_PLY_FIRST_GUESSES: usize = 50;
+
+const ALL_CORRECT_CODE: usize = PATTERN_SPACE - 1;
+
+/// Returns the fewest additional guesses that guarantee a solve from the current position.
+///
+/// The count includes the final winning guess, so a single remaining candidate yields `1`. The
+/// search is exact: it considers every allowed word at every step rather than estimating. To
+/// stay tractable it only runs when at most [`OPTIMAL_SEARCH_LIMIT`] candidates remain and
+/// returns `None` otherwise. Fibble games also return `None`, since lied feedback can never
+/// guarantee a solve. The guarantee is worst-case, so it also holds against an Absurdle host.
+pub fn optimal_remaining_guesses(game: &Wordle) -> Option<usize> {
+    if game.mode() == GameMode::Fibble {
+        return None;
+    }
+    optimal_guesses_among(&remaining_secrets(game))
+}
+
+fn optimal_guesses_among(candidates: &[&str]) -> Option<usize> {
+    if candidates.is_empty() || candidates.len() > OPTIMAL_SEARCH_LIMIT {
+        return None;
+    }
+
+    let mut sorted = candidates.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let mut memo = HashMap::new();
+    // Guessing each candidate in turn always works, so the search terminates by `len()`.
+    (1..=sorted.len()).find(|&depth| solvable_within(&sorted, depth, &mut memo))
+}
+
+/// Whether every secret in `candidates` (sorted) can be guessed within `depth` more guesses.
+fn solvable_within<'a>(
+    candidates: &[&'a str],
+    depth: usize,
+    memo: &mut HashMap<(Vec<&'a str>, usize), bool>,
+) -> bool {
+    match (candidates.len(), depth) {
+        (0, _) => return true,
+        (_, 0) => return false,
+        (1, _) => return true,
+        (_, 1) => return false,
+        (2, _) => return true,
+        _ => {}
+    }
+
+    let key = (candidates.to_vec(), depth);
+    if let Some(&known) = memo.get(&key) {
+        return known;
+    }
+
+    let result = partitions(candidates).iter().any(|buckets| {
+        buckets
+            .iter()
+            .all(|bucket| solvable_within(bucket, depth - 1, memo))
+    });
+    memo.insert(key, result);
+    result
+}
+
+/// Splits `candidates` by the pattern each allowed guess would show, keeping one guess per
+/// distinct split. Buckets exclude the secret a guess solves outright, and the splits are
+/// ordered so the most even ones are tried first.
+fn partitions<'a>(candidates: &[&'a str]) -> Vec<Vec<Vec<&'a str>>> {
+    let mut seen = HashSet::new();
+    let mut splits = Vec::new();
+    let secrets: Vec<Word> = candidates.iter().map(|word| Word::known(word)).collect();
+    let guesses = secrets
+        .iter()
+        .chain(allowed_word_list())
+        .copied()
+        .collect::<Vec<Word>>();
+
+    for guess in guesses {
+        let codes: Vec<usize> = secrets
+            .iter()
+            .map(|secret| encode_pattern(&guess.pattern_against(secret)))
+            .collect();
+
+        let mut labels = Vec::with_capacity(codes.len());
+        let mut order: Vec<usize> = Vec::new();
+        for &code in &codes {
+            let label = if code == ALL_CORRECT_CODE {
+                0
+            } else if let Some(pos) = order.iter().position(|&seen| seen == code) {
+                pos + 1
+            } else {
+                order.push(code);
+                order.len()
+            };
+            labels.push(label);
+        }
+
+        let solves_one = labels.contains(&0);
+        if order.len() == 1 && !solves_one {
+            continue;
+        }
+        if !seen.insert(labels.clone()) {
+            continue;
+        }
+
+        let mut buckets = vec![Vec::new(); order.len()];
+        for (secret, label) in candidates.iter().zip(&labels) {
+            if *label > 0 {
+                buckets[label - 1].push(*secret);
+            }
+        }
+        splits.push(buckets);
+    }
+
+    splits.sort_by_key(|buckets| buckets.iter().map(Vec::len).max().unwrap_or(0));
+    splits
+}
+
+/// Returns the first guess that leaves the least expected information after two guesses.
+///
+/// Each first guess splits the candidates into patterns; for every pattern the best follow-up
+/// is chosen by entropy among the secrets surviving that pattern, and the leftover uncertainty
+/// (`log2(bucket) - follow_up_bits`) is averaged over the patterns. Only the
+/// [`TWO_PLY_FIRST_GUESSES`] best first guesses by plain entropy are expanded, so the cost is
+/// one full allowed-word scan plus `O(TWO_PLY_FIRST_GUESSES * N^2)` pattern evaluations for
+/// `N` remaining candidates. Ties go to the guess with higher single-step entropy.
+pub fn best_two_ply_guess(game: &Wordle) -> Option<GuessEntropy> {
+    two_ply_guess_among(&remaining_secrets(game))
+}
+
+fn two_ply_guess_among(candidates: &[&str]) -> Option<GuessEntropy> {
+    ranked_information_guesses(candidates, TWO_PLY_FIRST_GUESSES)
+        .into_iter()
+        .map(|first| {
+            let cost = two_ply_cost(candidates, first.guess());
+            (first, cost)
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(first, _)| first)
+}
+
+/// The expected bits still unknown after playing `first` and then the best follow-up drawn
+/// from the surviving secrets.
+fn two_ply_cost(candidates: &[&str], first: &str) -> f64 {
+    let first = Word::known(first);
+    let mut buckets: HashMap<usize, Vec<&str>> = HashMap::new();
+    for secret in candidates {
+        let code = encode_pattern(&first.pattern_against(&Word::known(secret)));
+        if code != ALL_CORRECT_CODE {
+            buckets.entry(code).or_default().push(secret);
+        }
+    }
+
+    let total = candidates.len() as f64;
+    buckets
+        .values()
+        .filter(|bucket| bucket.len() > 1)
+        .map(|bucket| {
+            let follow_up_bits = bucket
+                .iter()
+                .filter_map(|guess| analyze_guess_against(guess, bucket.iter().copied()).ok())
+                .map(|entropy| entropy.entropy_bits())
+                .fold(0.0, f64::max);
+            let leftover = (bucket.len() as f64).log2() - follow_up_bits;
+            bucket.len() as f64 / total * leftover
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optimal_guesses_for_trivial_sets() {
+        assert_eq!(optimal_guesses_among(&["CIGAR"]), Some(1));
+        assert_eq!(optimal_guesses_among(&["CIGAR", "CHAIR"]), Some(2));
+        assert_eq!(optimal_guesses_among(&[]), None);
+    }
+
+    #[test]
+    fn optimal_guesses_needs_a_splitting_guess_for_rhymes() {
+        let family = [
+            "BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH", "WATCH",
+        ];
+        assert_eq!(optimal_guesses_among(&family), Some(3));
+    }
+
+    #[test]
+    fn two_ply_beats_greedy_on_rhyming_pool() {
+        let pool = ["LOLLY", "HOLLY", "JOLLY", "GOLLY", "FOLLY", "COLBY"];
+        let greedy = crate::information_guess_among(&pool).unwrap();
+        let two_ply = two_ply_guess_among(&pool).unwrap();
+        let greedy_cost = two_ply_cost(&pool, greedy.guess());
+        let chosen_cost = two_ply_cost(&pool, two_ply.guess());
+        assert_eq!(chosen_cost, 0.0);
+        assert!(chosen_cost < greedy_cost);
+    }
+
+    #[test]
+    fn optimal_guesses_declines_large_sets() {
+        let secrets: Vec<&str> = crate::secret_words()
+            .iter()
+            .take(OPTIMAL_SEARCH_LIMIT + 1)
+            .map(|word| word.as_str())
+            .collect();
+        assert_eq!(optimal_guesses_among(&secrets), None);
+    }
+
+    #[test]
+    fn optimal_remaining_guesses_after_a_solve() {
+        let mut game = Wordle::new("cigar").unwrap();
+        game.submit_guess("cigar").unwrap();
+        assert_eq!(optimal_remaining_guesses(&game), Some(1));
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{
+    best_information_guess_with, compare_guess_scores, for_each_guess_analysis, remaining_secrets,
+    remaining_secrets_iter, secret_words, GameMode, GuessEntropy, PatternMatrix, SuggestOptions,
+    Wordle, WordleError,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// The best information guess against the full secret list, fixed so that solving does not
+/// rescan every allowed word on the first turn.
+pub const SOLVER_OPENER: &str = "SOARE";
+
+/// Plays a game against `secret`, returning the guesses the solver made in order.
+///
+/// Every turn after the fixed [`SOLVER_OPENER`] plays [`best_information_guess_with`] with
+/// `exclude_played` set, switching to a remaining candidate once at most two are left, so
+/// words already guessed are never repeated and Fibble cannot loop on a lied row. Fibble lies
+/// are drawn from a fixed seed so the same secret always yields the same sequence. Errors with
+/// [`WordleError::Unsolved`] if the mode's attempt limit runs out first.
+pub fn solve(secret: &str, mode: GameMode) -> Result<Vec<String>, WordleError> {
+    solve_with(secret, mode, None)
+}
+
+/// Like [`solve`], reading each turn's patterns from `matrix` when one is given.
+fn solve_with(
+    secret: &str,
+    mode: GameMode,
+    matrix: Option<&PatternMatrix>,
+) -> Result<Vec<String>, WordleError> {
+    let mut game = Wordle::new_with_mode(secret, mode)?;
+    let mut rng = StdRng::seed_from_u64(0);
+
+    while !game.is_over() {
+        let guess = next_guess(&game, matrix);
+        game.submit_guess_with_rng(&guess, &mut rng)?;
+        if game.is_solved() {
+            return Ok(game
+                .guesses()
+                .iter()
+                .map(|row| row.guess().to_string())
+                .collect());
+        }
+    }
+
+    Err(WordleError::Unsolved {
+        attempts: mode.max_attempts(),
+    })
+}
+
+/// How [`solve`] fared across many secrets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveStats {
+    distribution: Vec<usize>,
+    failures: usize,
+}
+
+impl SolveStats {
+    fn new(mode: GameMode) -> Self {
+        Self {
+            distribution: vec![0; mode.max_attempts()],
+            failures: 0,
+        }
+    }
+
+    fn record(&mut self, result: Result<Vec<String>, WordleError>) {
+        match result {
+            Ok(guesses) => self.distribution[guesses.len() - 1] += 1,
+            Err(_) => self.failures += 1,
+        }
+    }
+
+    /// Returns how many games were solved in each number of guesses; index `0` counts
+    /// one-guess solves, up to the mode's [`GameMode::max_attempts`].
+    ///
+    /// This is a slice rather than an array because its length follows the mode: six entries
+    /// for Wordle but nine for Fibble, and the mode is only known at run time.
+    pub fn distribution(&self) -> &[usize] {
+        &self.distribution
+    }
+
+    /// Returns how many secrets were not solved within the attempt limit.
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    /// Returns how many games were played, solved or not.
+    pub fn games(&self) -> usize {
+        self.distribution.iter().sum::<usize>() + self.failures
+    }
+
+    /// Returns the mean number of guesses over the solved games, or `0.0` if none were solved.
+    pub fn mean_guesses(&self) -> f64 {
+        let solved: usize = self.distribution.iter().sum();
+        if solved == 0 {
+            return 0.0;
+        }
+        let guesses: usize = self
+            .distribution
+            .iter()
+            .enumerate()
+            .map(|(idx, count)| (idx + 1) * count)
+            .sum();
+        guesses as f64 / solved as f64
+    }
+}
+
+/// Runs [`solve`] against every bundled secret and tallies the results.
+///
+/// This plays thousands of games, so it builds one [`PatternMatrix`] up front and reads every
+/// turn's patterns from it; expect it to take a while all the same. With the `rayon` feature
+/// the games run in parallel.
+pub fn simulate_all(mode: GameMode) -> SolveStats {
+    let secrets: Vec<&str> = secret_words().iter().map(|word| word.as_str()).collect();
+    simulate_among(&secrets, mode, Some(&PatternMatrix::build()))
+}
+
+#[cfg(not(feature = "rayon"))]
+fn simulate_among(secrets: &[&str], mode: GameMode, matrix: Option<&PatternMatrix>) -> SolveStats {
+    let mut stats = SolveStats::new(mode);
+    for secret in secrets {
+        stats.record(solve_with(secret, mode, matrix));
+    }
+    stats
+}
+
+#[cfg(feature = "rayon")]
+fn simulate_among(secrets: &[&str], mode: GameMode, matrix: Option<&PatternMatrix>) -> SolveStats {
+    let results: Vec<_> = secrets
+        .par_iter()
+        .map(|secret| solve_with(secret, mode, matrix))
+        .collect();
+    let mut stats = SolveStats::new(mode);
+    for result in results {
+        stats.record(result);
+    }
+    stats
+}
+
+/// Rates how hard `secret` is as the number of guesses [`solve`] needs to find it.
+///
+/// Secrets the solver fails to find within the mode's attempt limit rate one more than that
+/// limit, so they sort above every solved secret. Errors if `secret` is not a valid secret.
+pub fn secret_difficulty(secret: &str, mode: GameMode) -> Result<f64, WordleError> {
+    difficulty_with(secret, mode, None)
+}
+
+fn difficulty_with(
+    secret: &str,
+    mode: GameMode,
+    matrix: Option<&PatternMatrix>,
+) -> Result<f64, WordleError> {
+    match solve_with(secret, mode, matrix) {
+        Ok(guesses) => Ok(guesses.len() as f64),
+        Err(WordleError::Unsolved { attempts }) => Ok((attempts + 1) as f64),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the `n` bundled secrets with the highest [`secret_difficulty`], hardest first.
+///
+/// Secrets with equal difficulty are ordered alphabetically. Like [`simulate_all`], this solves
+/// every secret against one shared [`PatternMatrix`], so it is slow; with the `rayon` feature
+/// the games run in parallel.
+pub fn hardest_secrets(n: usize, mode: GameMode) -> Vec<(&'static str, f64)> {
+    let secrets: Vec<&'static str> = secret_words().iter().map(|word| word.as_str()).collect();
+    hardest_among(&secrets, n, mode, Some(&PatternMatrix::build()))
+}
+
+fn hardest_among<'a>(
+    secrets: &[&'a str],
+    n: usize,
+    mode: GameMode,
+    matrix: Option<&PatternMatrix>,
+) -> Vec<(&'a str, f64)> {
+    let mut rated = rate_secrets(secrets, mode, matrix);
+    rated.sort_by(|(a_word, a), (b_word, b)| {
+        b.partial_cmp(a)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a_word.cmp(b_word))
+    });
+    rated.truncate(n);
+    rated
+}
+
+#[cfg(not(feature = "rayon"))]
+fn rate_secrets<'a>(
+    secrets: &[&'a str],
+    mode: GameMode,
+    matrix: Option<&PatternMatrix>,
+) -> Vec<(&'a str, f64)> {
+    secrets
+        .iter()
+        .filter_map(|secret| Some((*secret, difficulty_with(secret, mode, matrix).ok()?)))
+        .collect()
+}
+
+#[cfg(feature = "rayon")]
+fn rate_secrets<'a>(
+    secrets: &[&'a str],
+    mode: GameMode,
+    matrix: Option<&PatternMatrix>,
+) -> Vec<(&'a str, f64)> {
+    secrets
+        .par_iter()
+        .filter_map(|secret| Some((*secret, difficulty_with(secret, mode, matrix).ok()?)))
+        .collect()
+}
+
+fn next_guess(game: &Wordle, matrix: Option<&PatternMatrix>) -> String {
+    if game.guesses().is_empty() {
+        return SOLVER_OPENER.to_string();
+    }
+
+    let played = |word: &str| game.guesses().iter().any(|row| row.guess() == word);
+    let candidates: Vec<&str> = remaining_secrets_iter(game)
+        .filter(|word| !played(word))
+        .collect();
+    if candidates.len() > 2 {
+        let best = match matrix {
+            Some(matrix) => matrix_information_guess(game, matrix),
+            None => {
+                let options = SuggestOptions {
+                    exclude_played: true,
+                    ..SuggestOptions::default()
+                };
+                best_information_guess_with(game, &options)
+            }
+        };
+        if let Some(best) = best {
+            return best.guess().to_string();
+        }
+    }
+    candidates
+        .first()
+        .map(|word| word.to_string())
+        .unwrap_or_else(|| SOLVER_OPENER.to_string())
+}
+
+/// The guess [`best_information_guess_with`] picks with `exclude_played` set, scored from
+/// `matrix` instead of pair by pair.
+fn matrix_information_guess(game: &Wordle, matrix: &PatternMatrix) -> Option<GuessEntropy> {
+    let candidates = remaining_secrets(game);
+    let live: HashSet<&str> = candidates.iter().copied().collect();
+    let mut best: Option<GuessEntropy> = None;
+    for_each_guess_analysis(
+        &candidates,
+        Some(matrix),
+        |_, _| {},
+        |analysis| {
+            if game
+                .guesses()
+                .iter()
+                .any(|row| row.guess() == analysis.guess())
+            {
+                return;
+            }
+            let score = (analysis.guess(), analysis.entropy_bits());
+            if best.as_ref().is_none_or(|current| {
+                compare_guess_scores(score, (current.guess(), current.entropy_bits()), &live)
+                    .is_gt()
+            }) {
+                best = Some(analysis);
+            }
+        },
+    )
+    .ok()?;
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_known_secrets_within_six_guesses() {
+        for secret in ["CIGAR", "REBUT", "HUMPH", "SISSY", "AWAKE"] {
+            let guesses = solve(secret, GameMode::Wordle).unwrap();
+            assert!(guesses.len() <= 6, "{secret} took {guesses:?}");
+            assert_eq!(guesses.first().map(String::as_str), Some(SOLVER_OPENER));
+            assert_eq!(guesses.last().map(String::as_str), Some(secret));
+        }
+    }
+
+    #[test]
+    fn simulation_tallies_guess_counts() {
+        let secrets = ["CIGAR", "REBUT", "SOARE"];
+        let stats = simulate_among(&secrets, GameMode::Wordle, None);
+        let lengths: Vec<usize> = secrets
+            .iter()
+            .map(|secret| solve(secret, GameMode::Wordle).unwrap().len())
+            .collect();
+        assert_eq!(stats.games(), 3);
+        assert_eq!(stats.failures(), 0);
+        assert_eq!(stats.distribution().len(), 6);
+        assert_eq!(stats.distribution()[0], 1);
+        let expected_mean = lengths.iter().sum::<usize>() as f64 / 3.0;
+        assert!((stats.mean_guesses() - expected_mean).abs() < 1e-9);
+    }
+
+    #[test]
+    fn crowded_secrets_rate_harder_than_distinctive_ones() {
+        // WATCH shares its pattern with BATCH, CATCH, HATCH, LATCH, MATCH and PATCH for a
+        // long time; CIGAR is pinned down quickly.
+        let watch = secret_difficulty("WATCH", GameMode::Wordle).unwrap();
+        let cigar = secret_difficulty("CIGAR", GameMode::Wordle).unwrap();
+        assert!(watch > cigar, "WATCH rated {watch}, CIGAR rated {cigar}");
+
+        let hardest = hardest_among(&["CIGAR", "WATCH", "SOARE"], 2, GameMode::Wordle, None);
+        assert_eq!(hardest.len(), 2);
+        assert_eq!(hardest[0], ("WATCH", watch));
+        assert_eq!(hardest[1].0, "CIGAR");
+        assert!(secret_difficulty("zzzzz", GameMode::Wordle).is_err());
+    }
+
+    #[test]
+    fn matrix_turns_match_direct_scoring() {
+        let matrix = PatternMatrix::build();
+        for (secret, mode) in [
+            ("CIGAR", GameMode::Wordle),
+            ("WATCH", GameMode::Wordle),
+            ("SISSY", GameMode::Fibble),
+        ] {
+            assert_eq!(
+                solve_with(secret, mode, Some(&matrix)),
+                solve(secret, mode),
+                "{secret}"
+            );
+        }
+        let secrets = ["CIGAR", "REBUT", "SOARE"];
+        assert_eq!(
+            simulate_among(&secrets, GameMode::Wordle, Some(&matrix)),
+            simulate_among(&secrets, GameMode::Wordle, None)
+        );
+    }
+
+    #[test]
+    fn solves_fibble_games() {
+        let guesses = solve("cigar", GameMode::Fibble).unwrap();
+        assert!(guesses.len() <= GameMode::Fibble.max_attempts());
+        assert_eq!(guesses.last().map(String::as_str), Some("CIGAR"));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    allowed_words, compute_pattern_digits, encode_pattern, secret_words, GuessEntropy, WordleError,
+    PATTERN_SPACE, WORD_LENGTH,
+};
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::str::FromStr;
+
+static ALLOWED_WORDS: Lazy<Vec<Word>> = Lazy::new(|| {
+    allowed_words()
+        .iter()
+        .map(|word| Word::known(word))
+        .collect()
+});
+static SECRET_WORDS: Lazy<Vec<Word>> = Lazy::new(|| {
+    secret_words()
+        .iter()
+        .map(|word| Word::known(word))
+        .collect()
+});
+
+/// A validated, uppercase `WORD_LENGTH`-letter word stored inline as ASCII bytes.
+///
+/// Words are `Copy` and compare by value, so hot loops can score them without the UTF-8
+/// and length checks that `&str` arguments need on every call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Word([u8; WORD_LENGTH]);
+
+impl Word {
+    /// Uppercases `word` and checks it has `WORD_LENGTH` ASCII letters.
+    ///
+    /// This does not check any dictionary; see [`crate::allowed_words`] for that.
+    pub fn parse(word: &str) -> Result<Self, WordleError> {
+        if !word.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            return Err(WordleError::NonAlphabetic {
+                word: word.to_string(),
+            });
+        }
+        if word.len() != WORD_LENGTH {
+            return Err(WordleError::InvalidLength {
+                expected: WORD_LENGTH,
+                found: word.len(),
+            });
+        }
+
+        let mut bytes = [0u8; WORD_LENGTH];
+        for (slot, byte) in bytes.iter_mut().zip(word.bytes()) {
+            *slot = byte.to_ascii_uppercase();
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Wraps a word the crate has already validated, such as a bundled list entry or a
+    /// remaining secret, without reporting errors.
+    pub(crate) fn known(word: &str) -> Self {
+        Self::parse(word).expect("the crate only passes validated words")
+    }
+
+    /// Returns the uppercase letters as bytes.
+    pub fn as_bytes(&self) -> &[u8; WORD_LENGTH] {
+        &self.0
+    }
+
+    /// Returns the word as a string slice.
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("words hold only ASCII letters")
+    }
+
+    /// Returns the pattern digits this word, played as a guess, shows against `secret`:
+    /// `0` absent, `1` present, `2` correct.
+    pub fn pattern_against(&self, secret: &Word) -> [u8; WORD_LENGTH] {
+        compute_pattern_digits::<WORD_LENGTH>(&secret.0, &self.0)
+    }
+}
+
+/// The bundled allowed guesses as [`Word`]s, in the order of [`crate::allowed_words`].
+pub(crate) fn allowed_word_list() -> &'static [Word] {
+    &ALLOWED_WORDS
+}
+
+/// The bundled secrets as [`Word`]s, in the order of [`crate::secret_words`].
+pub(crate) fn secret_word_list() -> &'static [Word] {
+    &SECRET_WORDS
+}
+
+/// Counts the patterns `guess` shows against each of `secrets`.
+///
+/// This is the inner loop behind [`crate::analyze_guess_against`]: the words are already
+/// validated, so each pair is scored straight from its bytes with no allocation. It does not
+/// check that `guess` is an allowed word.
+pub fn analyze_word_against(guess: Word, secrets: impl IntoIterator<Item = Word>) -> GuessEntropy {
+    let mut pattern_counts = vec![0usize; PATTERN_SPACE];
+    for secret in secrets {
+        pattern_counts[encode_pattern(&guess.pattern_against(&secret))] += 1;
+    }
+    GuessEntropy {
+        guess: guess.to_string(),
+        pattern_counts,
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Word {
+    type Err = WordleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pattern_for;
+    use fibble_core::{encode_pattern, pattern_code_to_string};
+
+    #[test]
+    fn parse_uppercases_and_validates() {
+        let word = Word::parse("cigar").unwrap();
+        assert_eq!(word.as_str(), "CIGAR");
+        assert_eq!(word.to_string(), "CIGAR");
+        assert_eq!("Cigar".parse::<Word>(), Ok(word));
+        assert_eq!(
+            Word::parse("tool"),
+            Err(WordleError::InvalidLength {
+                expected: WORD_LENGTH,
+                found: 4
+            })
+        );
+        assert_eq!(
+            Word::parse("ab1de"),
+            Err(WordleError::NonAlphabetic {
+                word: "ab1de".into()
+            })
+        );
+    }
+
+    #[test]
+    fn word_lists_follow_the_string_lists() {
+        assert_eq!(allowed_word_list().len(), allowed_words().len());
+        assert_eq!(secret_word_list()[0].as_str(), secret_words()[0]);
+
+        let guess = Word::parse("crane").unwrap();
+        let analysis = analyze_word_against(guess, secret_word_list().iter().copied());
+        let expected = crate::analyze_guess("crane").unwrap();
+        assert_eq!(analysis.guess(), "CRANE");
+        assert_eq!(analysis.pattern_counts(), expected.pattern_counts());
+    }
+
+    #[test]
+    fn pattern_against_matches_the_string_api() {
+        for (secret, guess) in [("cigar", "cairn"), ("apple", "allot"), ("abbey", "bobby")] {
+            let digits = Word::parse(guess)
+                .unwrap()
+                .pattern_against(&Word::parse(secret).unwrap());
+            assert_eq!(
+                pattern_code_to_string(encode_pattern(&digits), WORD_LENGTH),
+                pattern_for(secret, guess).unwrap()
+            );
+        }
+    }
+}
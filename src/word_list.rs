@@ -0,0 +1,412 @@
+use crate::{WordleError, WORD_LENGTH};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+#[cfg(feature = "flate2")]
+use std::io::Read;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::sync::Arc;
+
+/// The two bytes every gzip member starts with.
+#[cfg(feature = "flate2")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// A dictionary of uppercase words loaded at runtime, for use in place of the bundled lists.
+///
+/// Cloning is cheap, since the words are shared behind an `Arc`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordList {
+    word_length: usize,
+    words: Arc<[String]>,
+    lookup: Arc<HashSet<String>>,
+}
+
+impl WordList {
+    /// Builds a list of `WORD_LENGTH`-letter words from raw lines.
+    ///
+    /// Lines are trimmed and uppercased; blank lines, lines of any other length, and lines
+    /// with non-alphabetic characters are skipped. Errors if no words survive.
+    pub fn from_lines<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Self, WordleError> {
+        Self::from_lines_of_length(lines, WORD_LENGTH)
+    }
+
+    /// Like [`WordList::from_lines`], keeping words with `word_length` letters instead.
+    pub fn from_lines_of_length<'a>(
+        lines: impl Iterator<Item = &'a str>,
+        word_length: usize,
+    ) -> Result<Self, WordleError> {
+        let mut lookup = HashSet::new();
+        let words: Vec<String> = lines
+            .filter_map(|line| {
+                let word = line.trim();
+                if word.chars().count() != word_length
+                    || !word.chars().all(|ch| ch.is_ascii_alphabetic())
+                {
+                    return None;
+                }
+                Some(word.to_ascii_uppercase())
+            })
+            .filter(|word| lookup.insert(word.clone()))
+            .collect();
+
+        if words.is_empty() {
+            return Err(WordleError::EmptyWordList { word_length });
+        }
+
+        Ok(Self {
+            word_length,
+            words: words.into(),
+            lookup: Arc::new(lookup),
+        })
+    }
+
+    /// Reads a list of `WORD_LENGTH`-letter words from a file with one word per line.
+    ///
+    /// With the `flate2` feature, files starting with the gzip magic bytes are decompressed
+    /// first, whatever their extension.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_file_of_length(path, WORD_LENGTH)
+    }
+
+    /// Like [`WordList::from_file`], keeping words with `word_length` letters instead.
+    pub fn from_file_of_length(path: impl AsRef<Path>, word_length: usize) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        #[cfg(feature = "flate2")]
+        let bytes = if bytes.starts_with(&GZIP_MAGIC) {
+            gunzip(&bytes)?
+        } else {
+            bytes
+        };
+        Self::from_bytes_of_length(bytes, word_length)
+    }
+
+    /// Reads a gzip-compressed list of `WORD_LENGTH`-letter words, one per line.
+    ///
+    /// Unlike [`WordList::from_file`], this fails with [`ErrorKind::InvalidData`] when the
+    /// file is not gzip data.
+    #[cfg(feature = "flate2")]
+    pub fn from_gz_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_gz_file_of_length(path, WORD_LENGTH)
+    }
+
+    /// Like [`WordList::from_gz_file`], keeping words with `word_length` letters instead.
+    #[cfg(feature = "flate2")]
+    pub fn from_gz_file_of_length(path: impl AsRef<Path>, word_length: usize) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        if !bytes.starts_with(&GZIP_MAGIC) {
+            return Err(io::Error::new(ErrorKind::InvalidData, "not gzip data"));
+        }
+        Self::from_bytes_of_length(gunzip(&bytes)?, word_length)
+    }
+
+    fn from_bytes_of_length(bytes: Vec<u8>, word_length: usize) -> io::Result<Self> {
+        let contents =
+            String::from_utf8(bytes).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+        Self::from_lines_of_length(contents.lines(), word_length)
+            .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+    }
+
+    /// Returns the number of letters in every word of the list.
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Returns the words in file order, without duplicates.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Returns how many words the list holds.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether the list holds no words. Lists built by the constructors are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Whether `word` (already uppercase) is in the list.
+    pub fn contains(&self, word: &str) -> bool {
+        self.lookup.contains(word)
+    }
+}
+
+/// Decompresses every member of a gzip file, as `gzip -d` does for concatenated files.
+#[cfg(feature = "flate2")]
+fn gunzip(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    let mut decompressed = Vec::new();
+    flate2::read::MultiGzDecoder::new(bytes).read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+/// Which of the two lists passed to [`validate_lists`] an issue was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListKind {
+    Allowed,
+    Secrets,
+}
+
+impl fmt::Display for ListKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ListKind::Allowed => "allowed list",
+            ListKind::Secrets => "secret list",
+        })
+    }
+}
+
+/// A problem [`validate_lists`] found in a pair of word lists. `index` is the entry's
+/// zero-based position in its list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListIssue {
+    /// The entry has characters other than ASCII letters.
+    NonAlphabetic {
+        list: ListKind,
+        index: usize,
+        word: String,
+    },
+    /// The entry does not have `WORD_LENGTH` letters.
+    WrongLength {
+        list: ListKind,
+        index: usize,
+        word: String,
+        found: usize,
+    },
+    /// The entry repeats the one at `first_index`, ignoring case.
+    Duplicate {
+        list: ListKind,
+        index: usize,
+        word: String,
+        first_index: usize,
+    },
+    /// The secret is not an allowed guess, so it could never be guessed.
+    SecretNotAllowed { index: usize, word: String },
+}
+
+impl fmt::Display for ListIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListIssue::NonAlphabetic { list, index, word } => {
+                write!(f, "{list} entry {index}: {word:?} is not alphabetic")
+            }
+            ListIssue::WrongLength {
+                list,
+                index,
+                word,
+                found,
+            } => write!(
+                f,
+                "{list} entry {index}: {word:?} has {found} letters, expected {WORD_LENGTH}"
+            ),
+            ListIssue::Duplicate {
+                list,
+                index,
+                word,
+                first_index,
+            } => write!(
+                f,
+                "{list} entry {index}: {word:?} duplicates entry {first_index}"
+            ),
+            ListIssue::SecretNotAllowed { index, word } => {
+                write!(
+                    f,
+                    "secret list entry {index}: {word:?} is not in the allowed list"
+                )
+            }
+        }
+    }
+}
+
+/// Checks a candidate allowed/secret list pair and reports every problem instead of stopping
+/// at the first.
+///
+/// Entries are trimmed and compared case-insensitively; blank entries are ignored, as the
+/// loaders ignore them. Each malformed entry gets one issue (non-alphabetic before wrong
+/// length), and only well-formed secrets are checked against the allowed list. Issues come
+/// back in list order, allowed list first. An empty result means the pair is safe to load.
+pub fn validate_lists(allowed: &[String], secrets: &[String]) -> Vec<ListIssue> {
+    let mut issues = Vec::new();
+    let allowed: HashSet<String> = check_entries(ListKind::Allowed, allowed, &mut issues)
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect();
+    for (index, word) in check_entries(ListKind::Secrets, secrets, &mut issues) {
+        if !allowed.contains(&word) {
+            issues.push(ListIssue::SecretNotAllowed { index, word });
+        }
+    }
+    issues
+}
+
+/// Records malformed and duplicate entries of `list`, returning the well-formed, first-seen
+/// words uppercased alongside their indices.
+fn check_entries(
+    list: ListKind,
+    entries: &[String],
+    issues: &mut Vec<ListIssue>,
+) -> Vec<(usize, String)> {
+    let mut first_seen = HashMap::new();
+    let mut words = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let word = entry.trim();
+        if word.is_empty() {
+            continue;
+        }
+        if !word.chars().all(|ch| ch.is_ascii_alphabetic()) {
+            issues.push(ListIssue::NonAlphabetic {
+                list,
+                index,
+                word: word.to_string(),
+            });
+            continue;
+        }
+        if word.len() != WORD_LENGTH {
+            issues.push(ListIssue::WrongLength {
+                list,
+                index,
+                word: word.to_string(),
+                found: word.len(),
+            });
+            continue;
+        }
+        let uppercase = word.to_ascii_uppercase();
+        if let Some(&first_index) = first_seen.get(&uppercase) {
+            issues.push(ListIssue::Duplicate {
+                list,
+                index,
+                word: word.to_string(),
+                first_index,
+            });
+            continue;
+        }
+        first_seen.insert(uppercase.clone(), index);
+        words.push((index, uppercase));
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GameMode, Wordle};
+
+    #[test]
+    fn from_lines_trims_uppercases_and_filters() {
+        let list =
+            WordList::from_lines(["  cigar", "", "tool", "cr4ne", "Cigar", "vigor "].into_iter())
+                .unwrap();
+        assert_eq!(list.words(), ["CIGAR", "VIGOR"]);
+        assert!(list.contains("VIGOR"));
+    }
+
+    #[test]
+    fn rejects_lists_without_usable_words() {
+        assert_eq!(
+            WordList::from_lines(["tool", ""].into_iter()).unwrap_err(),
+            WordleError::EmptyWordList { word_length: 5 }
+        );
+    }
+
+    #[test]
+    fn from_file_reads_one_word_per_line() {
+        let path =
+            std::env::temp_dir().join(format!("fibble-word-list-{}.txt", std::process::id()));
+        fs::write(&path, "abcde\nfghij\n").unwrap();
+        let list = WordList::from_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(list.unwrap().words(), ["ABCDE", "FGHIJ"]);
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn gzipped_files_match_their_plain_text() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let plain = WordList::from_file(fixtures.join("words.txt")).unwrap();
+        assert_eq!(plain.len(), 288);
+
+        let gz = WordList::from_gz_file(fixtures.join("words.txt.gz")).unwrap();
+        assert_eq!(gz.words(), plain.words());
+        let stored = WordList::from_file(fixtures.join("words-stored.txt.gz")).unwrap();
+        assert_eq!(stored.words(), plain.words());
+
+        let err = WordList::from_gz_file(fixtures.join("words.txt")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn validate_lists_reports_each_kind_of_issue() {
+        let strings = |words: &[&str]| {
+            words
+                .iter()
+                .map(|word| word.to_string())
+                .collect::<Vec<_>>()
+        };
+        let allowed = strings(&["cigar", "rebut", "Cigar", "tool", ""]);
+        let secrets = strings(&["cigar", "cr4ne", "sissy", "rebut", "toolong"]);
+        assert_eq!(
+            validate_lists(&allowed, &secrets),
+            [
+                ListIssue::Duplicate {
+                    list: ListKind::Allowed,
+                    index: 2,
+                    word: "Cigar".into(),
+                    first_index: 0,
+                },
+                ListIssue::WrongLength {
+                    list: ListKind::Allowed,
+                    index: 3,
+                    word: "tool".into(),
+                    found: 4,
+                },
+                ListIssue::NonAlphabetic {
+                    list: ListKind::Secrets,
+                    index: 1,
+                    word: "cr4ne".into(),
+                },
+                ListIssue::WrongLength {
+                    list: ListKind::Secrets,
+                    index: 4,
+                    word: "toolong".into(),
+                    found: 7,
+                },
+                ListIssue::SecretNotAllowed {
+                    index: 2,
+                    word: "SISSY".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            ListIssue::SecretNotAllowed {
+                index: 2,
+                word: "SISSY".into()
+            }
+            .to_string(),
+            "secret list entry 2: \"SISSY\" is not in the allowed list"
+        );
+    }
+
+    #[test]
+    fn bundled_lists_validate_cleanly() {
+        assert_eq!(
+            validate_lists(crate::allowed_words(), crate::secret_words()),
+            []
+        );
+    }
+
+    #[test]
+    fn games_validate_against_custom_lists() {
+        let list = WordList::from_lines(["abcde", "fghij"].into_iter()).unwrap();
+        let mut game = Wordle::new_with_lists("abcde", GameMode::Wordle, &list).unwrap();
+        assert!(game.submit_guess("fghij").is_ok());
+        assert!(matches!(
+            game.submit_guess("cigar"),
+            Err(WordleError::UnknownWord { .. })
+        ));
+        assert!(matches!(
+            Wordle::new_with_lists("cigar", GameMode::Wordle, &list),
+            Err(WordleError::UnknownWord { .. })
+        ));
+    }
+}